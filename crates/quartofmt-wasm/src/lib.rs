@@ -12,6 +12,7 @@ pub fn format_qmd(input: &str, line_width: Option<usize>) -> String {
 #[wasm_bindgen]
 pub fn tokenize_debug(input: &str) -> String {
     // return a simple debug string; or serialize to JSON if you add serde
-    let tree = quartofmt::parse(input);
+    let block_tree = quartofmt::block_parser::BlockParser::new(input).parse();
+    let tree = quartofmt::inline_parser::InlineParser::new(block_tree).parse();
     format!("{tree:#?}")
 }
@@ -1,14 +1,45 @@
 use crate::syntax::{SyntaxKind, SyntaxNode};
+use attributes::split_trailing_attribute;
+use fenced_divs::is_div_fence_close;
+use footnotes::is_footnote_definition_start;
+use lists::is_list_marker_line;
+pub use lists::is_loose_list;
+pub(crate) use lists::{indent_width, strip_indent};
 use rowan::GreenNodeBuilder;
 
 fn init_logger() {
     let _ = env_logger::builder().is_test(true).try_init();
 }
 
+mod attributes;
+mod blockquotes;
+mod code_blocks;
+mod code_cells;
+mod definition_lists;
+mod fenced_divs;
+mod footnotes;
+mod html_blocks;
+mod latex;
+mod line_blocks;
+mod link_references;
+mod lists;
+mod math_blocks;
+mod tables;
+
 pub struct BlockParser<'a> {
     lines: Vec<&'a str>,
     pos: usize,
     builder: GreenNodeBuilder<'static>,
+    /// First line of the innermost container (e.g. a fenced div) being parsed.
+    container_start: usize,
+    /// Number of fenced divs enclosing the current position.
+    div_depth: usize,
+    /// Number of list items enclosing the current position.
+    list_depth: usize,
+    /// Number of containers whose content lines are being parsed in place.
+    nested_depth: usize,
+    /// The line after the last paragraph, if one has been parsed.
+    paragraph_end: Option<usize>,
 }
 
 impl<'a> BlockParser<'a> {
@@ -18,17 +49,29 @@ impl<'a> BlockParser<'a> {
             lines,
             pos: 0,
             builder: GreenNodeBuilder::new(),
+            container_start: 0,
+            div_depth: 0,
+            list_depth: 0,
+            nested_depth: 0,
+            paragraph_end: None,
         }
     }
 
     fn has_blank_line_before(&self) -> bool {
-        if self.pos == 0 {
+        if self.pos == self.container_start {
             true
         } else {
             self.lines[self.pos - 1].trim().is_empty()
         }
     }
 
+    /// Whether the current line directly follows a paragraph. Lists and
+    /// block quotes cannot interrupt a paragraph, but may follow any other
+    /// block without a blank line in between.
+    fn follows_paragraph(&self) -> bool {
+        self.pos > self.container_start && self.paragraph_end == Some(self.pos)
+    }
+
     fn try_parse_atx_heading(&mut self) -> bool {
         log::debug!("Trying to parse ATX heading at position {}", self.pos);
 
@@ -75,13 +118,128 @@ impl<'a> BlockParser<'a> {
             .token(SyntaxKind::AtxHeadingMarker.into(), &trimmed[..hashes]);
         self.builder.finish_node();
 
-        // Heading content node
+        self.emit_heading_content(content);
+
+        self.builder.finish_node(); // Heading
+
+        self.pos += 1;
+        true
+    }
+
+    /// Emit the text of a heading, with a trailing attribute block such as
+    /// `{#sec-results .unnumbered}` in its own node.
+    fn emit_heading_content(&mut self, text: &str) {
+        let (content, attribute) = match split_trailing_attribute(text) {
+            Some((content, ws, attribute)) => (content, Some((ws, attribute))),
+            None => (text, None),
+        };
+
         self.builder.start_node(SyntaxKind::HeadingContent.into());
         self.builder.token(SyntaxKind::TEXT.into(), content);
         self.builder.finish_node();
 
+        if let Some((ws, attribute)) = attribute {
+            if !ws.is_empty() {
+                self.builder.token(SyntaxKind::WHITESPACE.into(), ws);
+            }
+            self.emit_attribute(attribute);
+        }
+    }
+
+    /// Parse a setext heading: a single line of text underlined with `=`
+    /// (level 1) or `-` (level 2).
+    ///
+    /// ```markdown
+    /// Title
+    /// =====
+    /// ```
+    fn try_parse_setext_heading(&mut self) -> bool {
+        log::debug!("Trying to parse setext heading at position {}", self.pos);
+
+        if self.pos + 1 >= self.lines.len() {
+            return false;
+        }
+        let line = self.lines[self.pos];
+        let underline = self.lines[self.pos + 1];
+
+        let text = strip_leading_spaces(line);
+        if text.trim().is_empty() || text.starts_with(' ') || !is_setext_underline(underline) {
+            return false;
+        }
+
+        // blank_before_header applies to setext headings as well
+        if !self.has_blank_line_before() {
+            return false;
+        }
+
+        self.builder.start_node(SyntaxKind::Heading.into());
+
+        let content = text.trim_end();
+        if text.len() < line.len() {
+            self.builder.token(
+                SyntaxKind::WHITESPACE.into(),
+                &line[..line.len() - text.len()],
+            );
+        }
+        self.emit_heading_content(content);
+        if content.len() < text.len() {
+            self.builder
+                .token(SyntaxKind::WHITESPACE.into(), &text[content.len()..]);
+        }
+        self.builder.token(SyntaxKind::NEWLINE.into(), "\n");
+
+        let marker = strip_leading_spaces(underline);
+        let marker_text = marker.trim_end();
+        if marker.len() < underline.len() {
+            self.builder.token(
+                SyntaxKind::WHITESPACE.into(),
+                &underline[..underline.len() - marker.len()],
+            );
+        }
+        self.builder
+            .start_node(SyntaxKind::SetextHeadingUnderline.into());
+        self.builder.token(SyntaxKind::TEXT.into(), marker_text);
+        self.builder.finish_node();
+        if marker_text.len() < marker.len() {
+            self.builder
+                .token(SyntaxKind::WHITESPACE.into(), &marker[marker_text.len()..]);
+        }
+        self.builder.token(SyntaxKind::NEWLINE.into(), "\n");
+
         self.builder.finish_node(); // Heading
 
+        self.pos += 2;
+        true
+    }
+
+    /// Parse a thematic break: three or more `*`, `-` or `_`, optionally
+    /// separated by spaces.
+    ///
+    /// Frontmatter delimiters and setext heading underlines take precedence,
+    /// as they are tried before this.
+    fn try_parse_thematic_break(&mut self) -> bool {
+        log::debug!("Trying to parse thematic break at position {}", self.pos);
+
+        if self.pos >= self.lines.len() || !is_thematic_break(self.lines[self.pos]) {
+            return false;
+        }
+        let line = self.lines[self.pos];
+        let marker = line.trim();
+        let leading = &line[..line.len() - line.trim_start().len()];
+        let trailing = &line[leading.len() + marker.len()..];
+
+        self.builder.start_node(SyntaxKind::HorizontalRule.into());
+        if !leading.is_empty() {
+            self.builder.token(SyntaxKind::WHITESPACE.into(), leading);
+        }
+        self.builder
+            .token(SyntaxKind::HorizontalRule.into(), marker);
+        if !trailing.is_empty() {
+            self.builder.token(SyntaxKind::WHITESPACE.into(), trailing);
+        }
+        self.builder.token(SyntaxKind::NEWLINE.into(), "\n");
+        self.builder.finish_node(); // HorizontalRule
+
         self.pos += 1;
         true
     }
@@ -98,6 +256,7 @@ impl<'a> BlockParser<'a> {
         if line.trim().is_empty() {
             self.builder.start_node(SyntaxKind::BlankLine.into());
             self.builder.token(SyntaxKind::BlankLine.into(), line);
+            self.builder.token(SyntaxKind::NEWLINE.into(), "\n");
             self.builder.finish_node();
             self.pos += 1;
 
@@ -109,110 +268,90 @@ impl<'a> BlockParser<'a> {
         false
     }
 
-    pub fn try_parse_fenced_code_block(&mut self) -> bool {
-        log::debug!("Trying to parse fenced code block at position {}", self.pos);
+    /// Parse a YAML metadata block delimited by `---` and `---` or `...`.
+    ///
+    /// At the beginning of the document this is the frontmatter. Pandoc also
+    /// allows metadata blocks later in the document, as long as they are
+    /// preceded by a blank line. In both cases the opening delimiter must not
+    /// be followed by a blank line, and a closing delimiter must exist.
+    ///
+    /// Pandoc drops a later block that does not parse as a YAML mapping, so
+    /// there the first line must be a key (`key:`) or a comment, and the
+    /// block may not contain blank lines.
+    pub fn try_parse_frontmatter(&mut self) -> bool {
+        log::debug!("Trying to parse frontmatter at position {}", self.pos);
 
         if self.pos >= self.lines.len() {
             return false;
         }
 
-        let line = self.lines[self.pos];
-        let trimmed = strip_leading_spaces(line);
-
-        // Check if this is a fenced code block opening
-        let (fence_char, fence_count) = if let Some(count) = get_fence_count(trimmed, '`') {
-            ('`', count)
-        } else if let Some(count) = get_fence_count(trimmed, '~') {
-            ('~', count)
-        } else {
-            return false;
-        };
-
-        // Must have at least 3 fence characters
-        if fence_count < 3 {
+        if self.lines[self.pos].trim_end() != "---" {
             return false;
         }
 
-        // blank_before_header: require blank line before, unless at BOF
         if !self.has_blank_line_before() {
             return false;
         }
 
-        // Extract info string (language, attributes, etc.)
-        let info_string = trimmed[fence_count..].trim();
-
-        // Start code block
-        self.builder.start_node(SyntaxKind::CodeBlock.into());
-
-        // Opening fence
-        self.builder.start_node(SyntaxKind::CodeFenceOpen.into());
-        self.builder
-            .token(SyntaxKind::CodeFenceMarker.into(), &trimmed[..fence_count]);
-        if !info_string.is_empty() {
-            self.builder.token(SyntaxKind::CodeInfo.into(), info_string);
+        // The opening delimiter must be directly followed by content
+        match self.lines.get(self.pos + 1) {
+            Some(next) if !next.trim().is_empty() => {}
+            _ => return false,
         }
-        self.builder.finish_node(); // CodeFenceOpen
 
-        self.pos += 1;
+        let at_document_start = self.pos == 0 && self.div_depth == 0 && self.nested_depth == 0;
+        if !at_document_start && !is_yaml_mapping_start(self.lines[self.pos + 1]) {
+            return false;
+        }
 
-        // Collect content lines until we find a closing fence
-        let mut content_lines = Vec::new();
-        let mut found_closing = false;
+        let Some(close) = (self.pos + 1..self.lines.len())
+            .take_while(|&i| at_document_start || !self.lines[i].trim().is_empty())
+            .find(|&i| {
+                let line = self.lines[i].trim_end();
+                line == "---" || line == "..."
+            })
+        else {
+            return false;
+        };
 
-        while self.pos < self.lines.len() {
-            let line = self.lines[self.pos];
-            let trimmed_line = strip_leading_spaces(line);
-
-            // Check if this is a valid closing fence
-            if let Some(closing_count) = get_fence_count(trimmed_line, fence_char)
-                && closing_count >= fence_count
-            {
-                // Make sure the rest of the line is empty (or just whitespace)
-                let after_fence = trimmed_line[closing_count..].trim();
-                if after_fence.is_empty() {
-                    found_closing = true;
-                    break;
-                }
-            }
+        self.builder.start_node(SyntaxKind::FRONTMATTER.into());
 
-            content_lines.push(line);
-            self.pos += 1;
-        }
+        self.emit_frontmatter_delim(self.pos);
 
-        // Add content
-        if !content_lines.is_empty() {
-            self.builder.start_node(SyntaxKind::CodeContent.into());
-            for (i, content_line) in content_lines.iter().enumerate() {
-                if i > 0 {
-                    self.builder.token(SyntaxKind::NEWLINE.into(), "\n");
-                }
-                self.builder.token(SyntaxKind::TEXT.into(), content_line);
-            }
-            self.builder.finish_node(); // CodeContent
+        self.builder
+            .start_node(SyntaxKind::FrontmatterContent.into());
+        for i in self.pos + 1..close {
+            self.builder.token(SyntaxKind::TEXT.into(), self.lines[i]);
+            self.builder.token(SyntaxKind::NEWLINE.into(), "\n");
         }
+        self.builder.finish_node(); // FrontmatterContent
 
-        // Closing fence (if found)
-        if found_closing {
-            let closing_line = self.lines[self.pos];
-            let closing_trimmed = strip_leading_spaces(closing_line);
-            let closing_count = get_fence_count(closing_trimmed, fence_char).unwrap();
-
-            self.builder.start_node(SyntaxKind::CodeFenceClose.into());
-            self.builder.token(
-                SyntaxKind::CodeFenceMarker.into(),
-                &closing_trimmed[..closing_count],
-            );
-            self.builder.finish_node(); // CodeFenceClose
+        self.emit_frontmatter_delim(close);
 
-            self.pos += 1;
-        }
+        self.builder.finish_node(); // FRONTMATTER
 
-        self.builder.finish_node(); // CodeBlock
+        log::debug!(
+            "Parsed frontmatter at lines {}..={}",
+            self.pos + 1,
+            close + 1
+        );
 
-        log::debug!("Parsed fenced code block, found_closing: {}", found_closing);
+        self.pos = close + 1;
         true
     }
 
+    fn emit_frontmatter_delim(&mut self, line_idx: usize) {
+        let line = self.lines[line_idx];
+        let delim = line.trim_end();
+        self.builder
+            .token(SyntaxKind::FrontmatterDelim.into(), delim);
+        if delim.len() < line.len() {
+            self.builder
+                .token(SyntaxKind::WHITESPACE.into(), &line[delim.len()..]);
+        }
+        self.builder.token(SyntaxKind::NEWLINE.into(), "\n");
+    }
+
     pub fn try_parse_paragraph(&mut self) -> bool {
         log::debug!("Trying to parse paragraph at position {}", self.pos);
 
@@ -228,14 +367,26 @@ impl<'a> BlockParser<'a> {
         // Start paragraph node
         self.builder.start_node(SyntaxKind::PARAGRAPH.into());
 
+        let start = self.pos;
         while self.pos < self.lines.len() {
             let line = self.lines[self.pos];
             if line.trim().is_empty() {
                 break;
             }
 
+            if self.pos > start && self.interrupts_paragraph(self.pos) {
+                break;
+            }
+
             // Add line as TEXT token (could be improved to handle inline elements)
-            self.builder.token(SyntaxKind::TEXT.into(), line);
+            let text = line.trim_start();
+            if text.len() < line.len() {
+                self.builder.token(
+                    SyntaxKind::WHITESPACE.into(),
+                    &line[..line.len() - text.len()],
+                );
+            }
+            self.builder.token(SyntaxKind::TEXT.into(), text);
             self.builder.token(SyntaxKind::NEWLINE.into(), "\n");
 
             self.pos += 1;
@@ -244,10 +395,48 @@ impl<'a> BlockParser<'a> {
         }
 
         self.builder.finish_node(); // PARAGRAPH
+        self.paragraph_end = Some(self.pos);
 
         true
     }
 
+    /// Whether the line at `idx` ends the current paragraph and starts a new
+    /// block, even though no blank line separates the two.
+    fn interrupts_paragraph(&self, idx: usize) -> bool {
+        let line = self.lines[idx];
+        (self.div_depth > 0 && is_div_fence_close(line))
+            || (self.list_depth > 0 && is_list_marker_line(line))
+            || is_footnote_definition_start(line)
+            || self.find_math_block_end(idx).is_some()
+            || self.starts_closed_backtick_code_block(idx)
+    }
+
+    /// Parse `lines` as the content of a nested container (e.g. a list item
+    /// with its indentation stripped), emitting the blocks in place.
+    ///
+    /// Only the text of `lines` ends up in the tree, so whatever the caller
+    /// stripped off, such as continuation indentation or `>` markers after
+    /// the first line, is dropped and the container is not lossless.
+    fn parse_nested_lines(&mut self, lines: Vec<&'a str>) {
+        let outer_lines = std::mem::replace(&mut self.lines, lines);
+        let outer_pos = std::mem::replace(&mut self.pos, 0);
+        let outer_start = std::mem::replace(&mut self.container_start, 0);
+        let outer_div_depth = std::mem::replace(&mut self.div_depth, 0);
+        let outer_paragraph_end = self.paragraph_end.take();
+        self.nested_depth += 1;
+
+        while self.pos < self.lines.len() {
+            self.parse_block();
+        }
+
+        self.nested_depth -= 1;
+        self.paragraph_end = outer_paragraph_end;
+        self.div_depth = outer_div_depth;
+        self.container_start = outer_start;
+        self.pos = outer_pos;
+        self.lines = outer_lines;
+    }
+
     pub fn parse(mut self) -> SyntaxNode {
         #[cfg(debug_assertions)]
         {
@@ -255,13 +444,10 @@ impl<'a> BlockParser<'a> {
         }
 
         self.builder.start_node(SyntaxKind::ROOT.into());
-        self.parse_document(); // <-- Add this line!
+        self.parse_document();
         self.builder.finish_node();
 
-        let flat_tree = SyntaxNode::new_root(self.builder.finish());
-
-        // Second pass: resolve container blocks
-        resolve_containers(flat_tree)
+        SyntaxNode::new_root(self.builder.finish())
     }
 
     fn parse_document(&mut self) {
@@ -270,215 +456,164 @@ impl<'a> BlockParser<'a> {
         log::debug!("Starting document parse");
 
         while self.pos < self.lines.len() {
-            let line = self.lines[self.pos];
+            self.parse_block();
+        }
 
-            log::debug!("Parsing line {}: {}", self.pos + 1, line);
+        self.builder.finish_node();
+    }
 
-            if self.try_parse_blank_line() {
-                continue;
-            }
+    /// Parse a single block starting at the current line.
+    fn parse_block(&mut self) {
+        let line = self.lines[self.pos];
 
-            if self.try_parse_atx_heading() {
-                continue;
-            }
+        log::debug!("Parsing line {}: {}", self.pos + 1, line);
 
-            if self.try_parse_fenced_code_block() {
-                continue;
-            }
+        if self.try_parse_blank_line() {
+            return;
+        }
 
-            if self.try_parse_paragraph() {
-                continue;
-            }
+        if self.try_parse_indented_code_block() {
+            return;
+        }
 
-            // If no other block matched, just skip the line (could be improved)
-            self.pos += 1;
+        if self.try_parse_frontmatter() {
+            return;
         }
 
-        self.builder.finish_node();
-    }
-}
+        if self.try_parse_fenced_div() {
+            return;
+        }
 
-fn resolve_containers(root: SyntaxNode) -> SyntaxNode {
-    use rowan::GreenNodeBuilder;
+        if self.try_parse_blockquote() {
+            return;
+        }
 
-    let mut builder = GreenNodeBuilder::new();
+        if self.try_parse_atx_heading() {
+            return;
+        }
 
-    // Copy the root node type
-    builder.start_node(root.kind().into());
+        if self.try_parse_fenced_code_block() {
+            return;
+        }
 
-    // Process the document children
-    if let Some(doc) = root.children().find(|n| n.kind() == SyntaxKind::DOCUMENT) {
-        builder.start_node(SyntaxKind::DOCUMENT.into());
-        resolve_container_children(&mut builder, &doc.children().collect::<Vec<_>>());
-        builder.finish_node();
-    }
+        if self.try_parse_math_block() {
+            return;
+        }
 
-    builder.finish_node();
-    SyntaxNode::new_root(builder.finish())
-}
+        if self.try_parse_html_block() {
+            return;
+        }
 
-fn resolve_container_children(builder: &mut GreenNodeBuilder<'static>, children: &[SyntaxNode]) {
-    let mut i = 0;
+        if self.try_parse_latex_environment() {
+            return;
+        }
 
-    while i < children.len() {
-        if let Some(blockquote_end) = try_identify_blockquote(children, i) {
-            // Found blockquote pattern from i..blockquote_end
-            build_blockquote_node(builder, &children[i..blockquote_end]);
-            i = blockquote_end;
-        } else {
-            // Regular node, copy as-is
-            copy_node_recursively(builder, &children[i]);
-            i += 1;
+        if self.try_parse_latex_command() {
+            return;
         }
-    }
-}
 
-fn try_identify_blockquote(children: &[SyntaxNode], start: usize) -> Option<usize> {
-    if start >= children.len() {
-        return None;
-    }
+        // A line of dashes opens a headerless table or is a thematic break,
+        // but never starts a list
+        if self.try_parse_dash_table() {
+            return;
+        }
 
-    // Check if this paragraph looks like a blockquote (starts with >)
-    let first_node = &children[start];
-    if first_node.kind() != SyntaxKind::PARAGRAPH {
-        return None;
-    }
+        if self.try_parse_thematic_break() {
+            return;
+        }
 
-    let text = first_node.text().to_string();
-    let first_line = text.lines().next().unwrap_or("");
+        if self.try_parse_list() {
+            return;
+        }
 
-    // Check if line has valid blockquote indentation (max 3 spaces before >)
-    if !is_valid_blockquote_line(first_line) {
-        return None;
-    }
+        if self.try_parse_footnote_definition() {
+            return;
+        }
 
-    // Find consecutive blockquote paragraphs and blank lines
-    let mut end = start + 1;
-    while end < children.len() {
-        let node = &children[end];
-        match node.kind() {
-            SyntaxKind::PARAGRAPH => {
-                let text = node.text().to_string();
-                let first_line = text.lines().next().unwrap_or("");
-                if is_valid_blockquote_line(first_line) {
-                    end += 1;
-                } else {
-                    break;
-                }
-            }
-            SyntaxKind::BlankLine => {
-                // Blank lines can be part of blockquotes
-                end += 1;
-            }
-            _ => break,
+        if self.try_parse_link_reference_definition() {
+            return;
         }
-    }
 
-    Some(end)
-}
+        if self.try_parse_setext_heading() {
+            return;
+        }
 
-fn is_valid_blockquote_line(line: &str) -> bool {
-    // Check for up to 3 spaces, then >, following Pandoc spec
-    if line.starts_with('>') {
-        return true;
-    }
-    if line.starts_with(' ') && line.len() > 1 && line[1..].starts_with('>') {
-        return true;
-    }
-    if line.starts_with("  ") && line.len() > 2 && line[2..].starts_with('>') {
-        return true;
-    }
-    if line.starts_with("   ") && line.len() > 3 && line[3..].starts_with('>') {
-        return true;
-    }
-    // 4 or more spaces before > is not a valid blockquote
-    false
-}
+        if self.try_parse_pipe_table() {
+            return;
+        }
 
-fn strip_blockquote_marker(line: &str) -> Option<&str> {
-    // Handle up to 3 spaces before >, then extract content after >
-    if let Some(stripped) = line.strip_prefix('>') {
-        // Remove optional space after >
-        stripped.strip_prefix(' ').or(Some(stripped))
-    } else if let Some(rest) = line.strip_prefix(' ')
-        && let Some(stripped) = rest.strip_prefix('>')
-    {
-        stripped.strip_prefix(' ').or(Some(stripped))
-    } else if let Some(rest) = line.strip_prefix("  ")
-        && let Some(stripped) = rest.strip_prefix('>')
-    {
-        stripped.strip_prefix(' ').or(Some(stripped))
-    } else if let Some(rest) = line.strip_prefix("   ")
-        && let Some(stripped) = rest.strip_prefix('>')
-    {
-        stripped.strip_prefix(' ').or(Some(stripped))
-    } else {
-        None
-    }
-}
+        if self.try_parse_grid_table() {
+            return;
+        }
 
-fn build_blockquote_node(builder: &mut GreenNodeBuilder<'static>, nodes: &[SyntaxNode]) {
-    builder.start_node(SyntaxKind::BlockQuote.into());
-
-    // Extract content from blockquote markers and recursively parse
-    let mut content_lines = Vec::new();
-
-    for node in nodes {
-        match node.kind() {
-            SyntaxKind::PARAGRAPH => {
-                let text = node.text().to_string();
-                for line in text.lines() {
-                    if let Some(stripped) = strip_blockquote_marker(line) {
-                        // Line has blockquote marker - extract content
-                        content_lines.push(stripped.to_string());
-                    } else {
-                        // Lazy line without marker - include as-is
-                        content_lines.push(line.to_string());
-                    }
-                }
-            }
-            SyntaxKind::BlankLine => {
-                content_lines.push(String::new());
-            }
-            _ => {}
+        if self.try_parse_line_block() {
+            return;
         }
-    }
 
-    if !content_lines.is_empty() {
-        let content = content_lines.join("\n");
-        if !content.trim().is_empty() {
-            // Create a sub-parser for the blockquote content - this enables recursion!
-            let sub_parser = BlockParser::new(&content);
-            let sub_tree = sub_parser.parse();
-
-            // Copy the sub-tree's document children into our blockquote
-            if let Some(doc) = sub_tree
-                .children()
-                .find(|n| n.kind() == SyntaxKind::DOCUMENT)
-            {
-                for child in doc.children() {
-                    copy_node_recursively(builder, &child);
-                }
-            }
+        if self.try_parse_definition_list() {
+            return;
+        }
+
+        if self.try_parse_paragraph() {
+            return;
         }
+
+        // If no other block matched, just skip the line (could be improved)
+        self.pos += 1;
     }
+}
 
-    builder.finish_node();
+/// Whether a line starting with the word `word` could be read as the start
+/// of a block rather than as the continuation of a paragraph. The formatter
+/// never wraps such a word to the start of a line.
+pub fn could_start_block(word: &str) -> bool {
+    let hashes = word.chars().take_while(|&c| c == '#').count();
+    is_list_marker_line(word)
+        || is_footnote_definition_start(word)
+        || is_thematic_break(word)
+        || is_setext_underline(word)
+        || (1..=6).contains(&hashes) && hashes == word.len()
+        || word.starts_with(['>', '|'])
+        || matches!(word, ":" | "~")
+        || ["$$", ":::", "```", "~~~"]
+            .iter()
+            .any(|prefix| word.starts_with(prefix))
 }
 
-fn copy_node_recursively(builder: &mut GreenNodeBuilder<'static>, node: &SyntaxNode) {
-    builder.start_node(node.kind().into());
+/// Whether `line` is a thematic break such as `***`, `- - -` or `___`,
+/// indented by at most three spaces.
+fn is_thematic_break(line: &str) -> bool {
+    let marker = strip_leading_spaces(line).trim_end();
+    let Some(c) = marker.chars().next() else {
+        return false;
+    };
+    matches!(c, '*' | '-' | '_')
+        && marker.chars().all(|m| m == c || m == ' ' || m == '\t')
+        && marker.chars().filter(|&m| m == c).count() >= 3
+}
 
-    for child in node.children_with_tokens() {
-        match child {
-            rowan::NodeOrToken::Node(n) => copy_node_recursively(builder, &n),
-            rowan::NodeOrToken::Token(t) => {
-                builder.token(t.kind().into(), t.text());
-            }
-        }
+/// Whether `line` can open a YAML mapping: a comment or a `key:` at the
+/// start of the line.
+fn is_yaml_mapping_start(line: &str) -> bool {
+    if line.starts_with('#') {
+        return true;
     }
+    let Some(colon) = line.find(':') else {
+        return false;
+    };
+    let key = &line[..colon];
+    let rest = &line[colon + 1..];
+    !key.is_empty()
+        && !key.starts_with(['-', '#'])
+        && !key.contains(char::is_whitespace)
+        && (rest.is_empty() || rest.starts_with([' ', '\t']))
+}
 
-    builder.finish_node();
+/// Whether `line` underlines a setext heading: a run of `=` or `-`, indented
+/// by at most three spaces.
+fn is_setext_underline(line: &str) -> bool {
+    let marker = strip_leading_spaces(line).trim_end();
+    !marker.is_empty() && (marker.chars().all(|c| c == '=') || marker.chars().all(|c| c == '-'))
 }
 
 fn strip_leading_spaces(line: &str) -> &str {
@@ -488,20 +623,24 @@ fn strip_leading_spaces(line: &str) -> &str {
         .unwrap_or(line)
 }
 
-fn get_fence_count(line: &str, fence_char: char) -> Option<usize> {
-    if !line.starts_with(fence_char) {
-        return None;
-    }
-
-    let count = line.chars().take_while(|&c| c == fence_char).count();
-    Some(count)
-}
-
 #[cfg(test)]
 mod tests {
     mod blanklines;
     mod blockquotes;
     mod code_blocks;
+    mod code_cells;
+    mod definition_lists;
+    mod fenced_divs;
+    mod footnotes;
+    mod frontmatter;
     mod headings;
     mod helpers;
+    mod html_blocks;
+    mod latex;
+    mod line_blocks;
+    mod link_references;
+    mod lists;
+    mod math_blocks;
+    mod tables;
+    mod thematic_breaks;
 }
@@ -0,0 +1,137 @@
+use super::BlockParser;
+use crate::syntax::SyntaxKind;
+
+/// A piece of an attribute block, `{#id .class key=value}`.
+enum AttributePart<'a> {
+    Id(&'a str),
+    Class(&'a str),
+    KeyValue(&'a str, &'a str),
+    Whitespace(&'a str),
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '-' | '_' | ':' | '.')
+}
+
+/// Length of an attribute value at the start of `text`: either quoted with
+/// `"` or `'`, or a run of characters up to whitespace or `}`.
+fn value_len(text: &str) -> Option<usize> {
+    let quote = text.chars().next()?;
+    if quote == '"' || quote == '\'' {
+        let mut escaped = false;
+        for (i, c) in text.char_indices().skip(1) {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                c if c == quote => return Some(i + 1),
+                _ => {}
+            }
+        }
+        return None;
+    }
+    let len = text
+        .find(|c: char| c.is_whitespace() || c == '}')
+        .unwrap_or(text.len());
+    (len > 0).then_some(len)
+}
+
+/// Parse `text` as an attribute block, which must span all of it.
+fn parse_attribute_block(text: &str) -> Option<Vec<AttributePart<'_>>> {
+    let inner = text.strip_prefix('{')?.strip_suffix('}')?;
+    let mut parts = Vec::new();
+    let mut rest = inner;
+    while !rest.is_empty() {
+        let ws = rest.len() - rest.trim_start().len();
+        if ws > 0 {
+            parts.push(AttributePart::Whitespace(&rest[..ws]));
+            rest = &rest[ws..];
+            continue;
+        }
+
+        let name_len = |s: &str| s.find(|c| !is_identifier_char(c)).unwrap_or(s.len());
+        let (part, len) = if rest.starts_with(['#', '.']) {
+            let len = 1 + name_len(&rest[1..]);
+            if len == 1 {
+                return None;
+            }
+            let name = &rest[..len];
+            if rest.starts_with('#') {
+                (AttributePart::Id(name), len)
+            } else {
+                (AttributePart::Class(name), len)
+            }
+        } else if rest.starts_with('-') && !rest[1..].starts_with(is_identifier_char) {
+            // `-` is short for `.unnumbered`
+            (AttributePart::Class("-"), 1)
+        } else {
+            let key_len = name_len(rest);
+            if key_len == 0 || !rest[key_len..].starts_with('=') {
+                return None;
+            }
+            let len = key_len + 1 + value_len(&rest[key_len + 1..])?;
+            let part = AttributePart::KeyValue(&rest[..key_len], &rest[key_len + 1..len]);
+            (part, len)
+        };
+
+        if !rest[len..].is_empty() && !rest[len..].starts_with(char::is_whitespace) {
+            return None;
+        }
+        parts.push(part);
+        rest = &rest[len..];
+    }
+    Some(parts)
+}
+
+/// Length of the attribute block at the start of `text`, if any.
+pub(super) fn attribute_block_len(text: &str) -> Option<usize> {
+    text.match_indices('}')
+        .map(|(close, _)| close + 1)
+        .find(|&len| parse_attribute_block(&text[..len]).is_some())
+}
+
+/// Split a trailing attribute block off `text`, returning the text before
+/// it, the whitespace in between and the attribute block itself.
+pub(super) fn split_trailing_attribute(text: &str) -> Option<(&str, &str, &str)> {
+    if !text.ends_with('}') {
+        return None;
+    }
+    text.match_indices('{')
+        .map(|(open, _)| open)
+        .find(|&open| parse_attribute_block(&text[open..]).is_some())
+        .map(|open| {
+            let before = text[..open].trim_end();
+            (before, &text[before.len()..open], &text[open..])
+        })
+}
+
+impl BlockParser<'_> {
+    /// Emit an Attribute node for an attribute block that has been split
+    /// off with [`split_trailing_attribute`].
+    pub(super) fn emit_attribute(&mut self, text: &str) {
+        let parts = parse_attribute_block(text).expect("attribute block was validated");
+
+        self.builder.start_node(SyntaxKind::Attribute.into());
+        self.builder.token(SyntaxKind::TEXT.into(), "{");
+        for part in parts {
+            match part {
+                AttributePart::Id(id) => self.builder.token(SyntaxKind::AttributeId.into(), id),
+                AttributePart::Class(class) => {
+                    self.builder.token(SyntaxKind::AttributeClass.into(), class)
+                }
+                AttributePart::KeyValue(key, value) => {
+                    self.builder
+                        .start_node(SyntaxKind::AttributeKeyValue.into());
+                    self.builder.token(SyntaxKind::AttributeKey.into(), key);
+                    self.builder.token(SyntaxKind::TEXT.into(), "=");
+                    self.builder.token(SyntaxKind::AttributeValue.into(), value);
+                    self.builder.finish_node();
+                }
+                AttributePart::Whitespace(ws) => {
+                    self.builder.token(SyntaxKind::WHITESPACE.into(), ws)
+                }
+            }
+        }
+        self.builder.token(SyntaxKind::TEXT.into(), "}");
+        self.builder.finish_node(); // Attribute
+    }
+}
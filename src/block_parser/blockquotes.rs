@@ -0,0 +1,76 @@
+use super::{BlockParser, strip_leading_spaces};
+use crate::syntax::SyntaxKind;
+
+/// Split a block quote line into its indentation, the `>` marker with the
+/// optional space after it, and the content. The marker may be indented by
+/// up to three spaces.
+fn split_blockquote_marker(line: &str) -> Option<(&str, &str, &str)> {
+    let after_indent = strip_leading_spaces(line);
+    let rest = after_indent.strip_prefix('>')?;
+    let content = rest.strip_prefix(' ').unwrap_or(rest);
+    let indent = &line[..line.len() - after_indent.len()];
+    let marker = &after_indent[..after_indent.len() - content.len()];
+    Some((indent, marker, content))
+}
+
+impl BlockParser<'_> {
+    /// Parse a block quote, whose lines start with `>`:
+    ///
+    /// ```markdown
+    /// > A quoted paragraph
+    /// that continues lazily.
+    /// >
+    /// > - A list
+    /// >
+    /// > > A nested quote
+    /// ```
+    ///
+    /// The content, with the markers removed, is parsed as nested blocks.
+    /// Lines without a marker continue the quote lazily as long as they
+    /// follow a non-blank line and would not interrupt a paragraph. As in
+    /// Pandoc, a block quote cannot interrupt a paragraph.
+    pub(super) fn try_parse_blockquote(&mut self) -> bool {
+        let Some((indent, marker, first)) = split_blockquote_marker(self.lines[self.pos]) else {
+            return false;
+        };
+        if self.follows_paragraph() {
+            return false;
+        }
+
+        log::debug!("Parsing block quote at line {}", self.pos + 1);
+
+        let mut content_lines = vec![first];
+        let mut end = self.pos + 1;
+        while end < self.lines.len() {
+            let line = self.lines[end];
+            if let Some((_, _, content)) = split_blockquote_marker(line) {
+                content_lines.push(content);
+            } else if !line.trim().is_empty()
+                && !content_lines.last().unwrap().trim().is_empty()
+                && !self.interrupts_paragraph(end)
+            {
+                // Lazy continuation
+                content_lines.push(line);
+            } else {
+                break;
+            }
+            end += 1;
+        }
+
+        self.builder.start_node(SyntaxKind::BlockQuote.into());
+        if !indent.is_empty() {
+            self.builder.token(SyntaxKind::WHITESPACE.into(), indent);
+        }
+        self.builder
+            .token(SyntaxKind::BlockQuoteMarker.into(), &marker[..1]);
+        if marker.len() > 1 {
+            self.builder
+                .token(SyntaxKind::WHITESPACE.into(), &marker[1..]);
+        }
+        self.parse_nested_lines(content_lines);
+        self.builder.finish_node(); // BlockQuote
+
+        self.pos = end;
+        true
+    }
+}
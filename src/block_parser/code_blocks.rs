@@ -0,0 +1,225 @@
+use super::code_cells::executable_cell_language;
+use super::lists::indent_width;
+use super::{BlockParser, strip_leading_spaces};
+use crate::syntax::SyntaxKind;
+
+/// Indentation, in columns, of the lines of an indented code block.
+const CODE_INDENT: usize = 4;
+
+/// The fence character and length of an opening code fence, i.e. three or
+/// more backticks or tildes indented by at most three spaces.
+///
+/// The info string of a backtick fence may not contain backticks, so that
+/// inline code at the start of a line is not mistaken for a fence.
+fn code_fence_open(line: &str) -> Option<(char, usize)> {
+    let trimmed = strip_leading_spaces(line);
+    let fence_char = trimmed.chars().next().filter(|&c| c == '`' || c == '~')?;
+    let count = fence_count(trimmed, fence_char);
+    if count < 3 || (fence_char == '`' && trimmed[count..].contains('`')) {
+        return None;
+    }
+    Some((fence_char, count))
+}
+
+/// Whether `line` closes a code block opened by `count` `fence_char`s: a
+/// fence of the same character that is at least as long, and nothing else.
+fn is_code_fence_close(line: &str, fence_char: char, count: usize) -> bool {
+    let trimmed = strip_leading_spaces(line);
+    let closing = fence_count(trimmed, fence_char);
+    closing >= count && trimmed[closing..].trim().is_empty()
+}
+
+fn fence_count(line: &str, fence_char: char) -> usize {
+    line.chars().take_while(|&c| c == fence_char).count()
+}
+
+fn is_indented_code_line(line: &str) -> bool {
+    !line.trim().is_empty() && indent_width(line) >= CODE_INDENT
+}
+
+impl BlockParser<'_> {
+    /// Whether a closed backtick code block starts at `idx`.
+    ///
+    /// Pandoc lets such a block interrupt a paragraph; tilde fences and
+    /// fences that are never closed are paragraph text instead.
+    pub(super) fn starts_closed_backtick_code_block(&self, idx: usize) -> bool {
+        match code_fence_open(self.lines[idx]) {
+            Some(('`', count)) => self.lines[idx + 1..]
+                .iter()
+                .any(|line| is_code_fence_close(line, '`', count)),
+            _ => false,
+        }
+    }
+
+    /// Parse a fenced code block, opened by three or more backticks or
+    /// tildes and closed by a fence of the same character that is at least
+    /// as long. An unclosed block extends to the end of the container.
+    ///
+    /// Every byte of the fence lines is kept: the opening fence holds the
+    /// marker, the `CodeInfo` string and its newline, and the closing fence
+    /// holds the marker and its newline. The newline ending the last content
+    /// line sits between `CodeContent` and the closing fence.
+    ///
+    /// Executable Quarto cells, such as ```` ```{r} ````, are CodeCell
+    /// nodes, with their leading `#|` option lines in a CellOptions node
+    /// before the content.
+    pub(super) fn try_parse_fenced_code_block(&mut self) -> bool {
+        log::debug!("Trying to parse fenced code block at position {}", self.pos);
+
+        if self.pos >= self.lines.len() {
+            return false;
+        }
+
+        let line = self.lines[self.pos];
+        let Some((fence_char, count)) = code_fence_open(line) else {
+            return false;
+        };
+
+        // Opening fence: [WS] CodeFenceMarker [WS] [CodeInfo] [WS] NEWLINE
+        let trimmed = strip_leading_spaces(line);
+        let after_marker = &trimmed[count..];
+        let info = after_marker.trim();
+        let language = executable_cell_language(info);
+
+        let kind = if language.is_some() {
+            SyntaxKind::CodeCell
+        } else {
+            SyntaxKind::CodeBlock
+        };
+        self.builder.start_node(kind.into());
+        self.builder.start_node(SyntaxKind::CodeFenceOpen.into());
+        let indent = &line[..line.len() - trimmed.len()];
+        if !indent.is_empty() {
+            self.builder.token(SyntaxKind::WHITESPACE.into(), indent);
+        }
+        self.builder
+            .token(SyntaxKind::CodeFenceMarker.into(), &trimmed[..count]);
+        if info.is_empty() {
+            if !after_marker.is_empty() {
+                self.builder
+                    .token(SyntaxKind::WHITESPACE.into(), after_marker);
+            }
+        } else {
+            let space = &after_marker[..after_marker.len() - after_marker.trim_start().len()];
+            if !space.is_empty() {
+                self.builder.token(SyntaxKind::WHITESPACE.into(), space);
+            }
+            self.builder.token(SyntaxKind::CodeInfo.into(), info);
+            let trailing = &after_marker[space.len() + info.len()..];
+            if !trailing.is_empty() {
+                self.builder.token(SyntaxKind::WHITESPACE.into(), trailing);
+            }
+        }
+        self.builder.token(SyntaxKind::NEWLINE.into(), "\n");
+        self.builder.finish_node(); // CodeFenceOpen
+
+        self.pos += 1;
+
+        let close = (self.pos..self.lines.len())
+            .find(|&i| is_code_fence_close(self.lines[i], fence_char, count));
+        let end = close.unwrap_or(self.lines.len());
+
+        if let Some(language) = language {
+            self.parse_cell_options(language, end);
+        }
+
+        if self.pos < end {
+            self.builder.start_node(SyntaxKind::CodeContent.into());
+            for (i, content_line) in self.lines[self.pos..end].iter().enumerate() {
+                if i > 0 {
+                    self.builder.token(SyntaxKind::NEWLINE.into(), "\n");
+                }
+                if !content_line.is_empty() {
+                    self.builder.token(SyntaxKind::TEXT.into(), content_line);
+                }
+            }
+            self.builder.finish_node(); // CodeContent
+            self.builder.token(SyntaxKind::NEWLINE.into(), "\n");
+        }
+        self.pos = end;
+
+        if let Some(close) = close {
+            let closing_line = self.lines[close];
+            let closing_trimmed = strip_leading_spaces(closing_line);
+            let marker = closing_trimmed.trim_end();
+
+            self.builder.start_node(SyntaxKind::CodeFenceClose.into());
+            let indent = &closing_line[..closing_line.len() - closing_trimmed.len()];
+            if !indent.is_empty() {
+                self.builder.token(SyntaxKind::WHITESPACE.into(), indent);
+            }
+            self.builder
+                .token(SyntaxKind::CodeFenceMarker.into(), marker);
+            if marker.len() < closing_trimmed.len() {
+                self.builder.token(
+                    SyntaxKind::WHITESPACE.into(),
+                    &closing_trimmed[marker.len()..],
+                );
+            }
+            self.builder.token(SyntaxKind::NEWLINE.into(), "\n");
+            self.builder.finish_node(); // CodeFenceClose
+
+            self.pos += 1;
+        }
+
+        self.builder.finish_node(); // CodeBlock or CodeCell
+
+        log::debug!(
+            "Parsed fenced code block, found_closing: {}",
+            close.is_some()
+        );
+        true
+    }
+
+    /// Parse an indented code block: lines indented by four or more
+    /// columns, possibly separated by blank lines.
+    ///
+    /// Indented code cannot interrupt a paragraph, so continuation lines of
+    /// a paragraph never get here.
+    pub(super) fn try_parse_indented_code_block(&mut self) -> bool {
+        log::debug!(
+            "Trying to parse indented code block at position {}",
+            self.pos
+        );
+
+        if self.pos >= self.lines.len() || !is_indented_code_line(self.lines[self.pos]) {
+            return false;
+        }
+
+        // The block runs up to the last indented line before a line that is
+        // neither blank nor indented
+        let mut end = self.pos;
+        for i in self.pos + 1..self.lines.len() {
+            let line = self.lines[i];
+            if is_indented_code_line(line) {
+                end = i;
+            } else if !line.trim().is_empty() {
+                break;
+            }
+        }
+
+        log::debug!(
+            "Parsing indented code block at lines {}..={}",
+            self.pos + 1,
+            end + 1
+        );
+
+        self.builder
+            .start_node(SyntaxKind::IndentedCodeBlock.into());
+        for line in &self.lines[self.pos..=end] {
+            let text = line.trim_start_matches([' ', '\t']);
+            let indent = &line[..line.len() - text.len()];
+            if !indent.is_empty() {
+                self.builder.token(SyntaxKind::WHITESPACE.into(), indent);
+            }
+            if !text.is_empty() {
+                self.builder.token(SyntaxKind::TEXT.into(), text);
+            }
+            self.builder.token(SyntaxKind::NEWLINE.into(), "\n");
+        }
+        self.builder.finish_node(); // IndentedCodeBlock
+
+        self.pos = end + 1;
+        true
+    }
+}
@@ -0,0 +1,123 @@
+use super::BlockParser;
+use crate::syntax::SyntaxKind;
+
+/// The language of an executable Quarto cell, e.g. `r` for ```` ```{r} ````
+/// or `python` for ```` ```{python echo=false} ````.
+///
+/// Pandoc attributes such as `{.python}` or `{#id}` and escaped cells such
+/// as `{{r}}` are plain code blocks.
+pub(super) fn executable_cell_language(info: &str) -> Option<&str> {
+    let inner = info.strip_prefix('{')?.strip_suffix('}')?;
+    let len = inner
+        .find(|c: char| c.is_whitespace() || c == ',')
+        .unwrap_or(inner.len());
+    let language = &inner[..len];
+    let valid = language
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic())
+        && language
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '+'));
+    valid.then_some(language)
+}
+
+/// The comment characters that, followed by `|`, start a cell option line
+/// in `language`.
+fn option_comment(language: &str) -> &'static str {
+    match language.to_ascii_lowercase().as_str() {
+        "ojs" | "js" | "javascript" | "typescript" | "dot" | "c" | "cpp" | "java" | "rust"
+        | "scala" => "//",
+        "sql" | "lua" | "haskell" => "--",
+        "mermaid" => "%%",
+        _ => "#",
+    }
+}
+
+/// Length of the `key:` at the start of an option, without the colon.
+fn option_key_len(text: &str) -> Option<usize> {
+    let len = text.find(|c: char| !(c.is_alphanumeric() || matches!(c, '-' | '_' | '.')))?;
+    (len > 0 && text[len..].starts_with(':')).then_some(len)
+}
+
+impl BlockParser<'_> {
+    /// Emit the option lines (`#| key: value`) at the start of the cell
+    /// content between `self.pos` and `end` as a CellOptions node, and
+    /// advance past them.
+    ///
+    /// Each option gets a CellOption node with its key and value. A key
+    /// starts a new option only at the indentation of the first option;
+    /// other lines, such as the items of a YAML list or the keys of a
+    /// nested mapping, continue the previous option.
+    pub(super) fn parse_cell_options(&mut self, language: &str, end: usize) {
+        let marker = format!("{}|", option_comment(language));
+        let count = self.lines[self.pos..end]
+            .iter()
+            .take_while(|line| line.starts_with(&marker))
+            .count();
+        if count == 0 {
+            return;
+        }
+
+        let indent_of = |line: &str| {
+            let rest = &line[marker.len()..];
+            rest.len() - rest.trim_start().len()
+        };
+        let base_indent = indent_of(self.lines[self.pos]);
+
+        self.builder.start_node(SyntaxKind::CellOptions.into());
+        for i in 0..count {
+            let line = self.lines[self.pos + i];
+            let rest = &line[marker.len()..];
+            let text = rest.trim_start();
+            let key_len = option_key_len(text).filter(|_| indent_of(line) == base_indent);
+
+            if i == 0 || key_len.is_some() {
+                if i > 0 {
+                    self.builder.finish_node(); // CellOption
+                }
+                self.builder.start_node(SyntaxKind::CellOption.into());
+            }
+            self.builder
+                .token(SyntaxKind::CellOptionMarker.into(), &marker);
+            if text.len() < rest.len() {
+                self.builder.token(
+                    SyntaxKind::WHITESPACE.into(),
+                    &rest[..rest.len() - text.len()],
+                );
+            }
+
+            let value = match key_len {
+                Some(len) => {
+                    self.builder
+                        .token(SyntaxKind::CellOptionKey.into(), &text[..len]);
+                    self.builder.token(SyntaxKind::TEXT.into(), ":");
+                    let after = &text[len + 1..];
+                    let value = after.trim_start();
+                    if value.len() < after.len() {
+                        self.builder.token(
+                            SyntaxKind::WHITESPACE.into(),
+                            &after[..after.len() - value.len()],
+                        );
+                    }
+                    value
+                }
+                None => text,
+            };
+            let trimmed = value.trim_end();
+            if !trimmed.is_empty() {
+                self.builder
+                    .token(SyntaxKind::CellOptionValue.into(), trimmed);
+            }
+            if trimmed.len() < value.len() {
+                self.builder
+                    .token(SyntaxKind::WHITESPACE.into(), &value[trimmed.len()..]);
+            }
+            self.builder.token(SyntaxKind::NEWLINE.into(), "\n");
+        }
+        self.builder.finish_node(); // CellOption
+        self.builder.finish_node(); // CellOptions
+
+        self.pos += count;
+    }
+}
@@ -0,0 +1,219 @@
+use super::BlockParser;
+use super::lists::{indent_width, strip_indent};
+use crate::syntax::SyntaxKind;
+
+/// Continuation lines of a definition are indented by four columns.
+const DEFINITION_INDENT: usize = 4;
+
+/// The byte ranges of the `:` or `~` marker at the start of a definition
+/// and of the whitespace after it, as `(marker_start, content_start)`.
+///
+/// As in Pandoc, the marker may be indented by up to two spaces and must be
+/// followed by whitespace. The whitespace up to the fourth column belongs to
+/// the marker, so that the content lines up with the continuation lines.
+fn definition_marker(line: &str) -> Option<(usize, usize)> {
+    let marker_start = line.len() - line.trim_start_matches(' ').len();
+    if marker_start > 2 || !line[marker_start..].starts_with([':', '~']) {
+        return None;
+    }
+    let after = &line[marker_start + 1..];
+    let remaining = DEFINITION_INDENT - (marker_start + 1);
+    let spaces = after.len() - after.trim_start_matches(' ').len();
+    let gap = if spaces >= remaining {
+        remaining
+    } else if spaces == 0 && after.starts_with('\t') {
+        1
+    } else {
+        after.len() - after.trim_start_matches([' ', '\t']).len()
+    };
+    if gap == 0 || after[gap..].trim().is_empty() {
+        return None;
+    }
+    Some((marker_start, marker_start + 1 + gap))
+}
+
+impl<'a> BlockParser<'a> {
+    fn starts_definition(&self, idx: usize) -> bool {
+        self.lines
+            .get(idx)
+            .is_some_and(|line| definition_marker(line).is_some())
+    }
+
+    /// Whether a definition list item starts at `idx`: a term line followed
+    /// by a definition, optionally after one blank line.
+    fn starts_definition_item(&self, idx: usize) -> bool {
+        let Some(term) = self.lines.get(idx) else {
+            return false;
+        };
+        if term.trim().is_empty() || self.starts_definition(idx) {
+            return false;
+        }
+        match self.lines.get(idx + 1) {
+            Some(next) if next.trim().is_empty() => self.starts_definition(idx + 2),
+            _ => self.starts_definition(idx + 1),
+        }
+    }
+
+    /// Parse a Pandoc definition list:
+    ///
+    /// ```markdown
+    /// Term 1
+    /// :   Definition 1
+    ///
+    /// Term 2
+    ///
+    /// ~   Definition 2a
+    ///
+    ///     Second paragraph of definition 2a.
+    ///
+    /// ~   Definition 2b
+    /// ```
+    ///
+    /// Each term is a single line. A blank line between a term and its
+    /// definition makes the definition loose; definitions continue with
+    /// lines indented by four columns, and loose ones also lazily while in
+    /// a paragraph.
+    pub(super) fn try_parse_definition_list(&mut self) -> bool {
+        if !self.starts_definition_item(self.pos) {
+            return false;
+        }
+
+        log::debug!("Parsing definition list at line {}", self.pos + 1);
+
+        self.builder.start_node(SyntaxKind::DefinitionList.into());
+        loop {
+            self.parse_definition_item();
+
+            // Items may be separated by any number of blank lines
+            let mut next = self.pos;
+            while next < self.lines.len() && self.lines[next].trim().is_empty() {
+                next += 1;
+            }
+            if !self.starts_definition_item(next) {
+                break;
+            }
+            while self.pos < next {
+                self.try_parse_blank_line();
+            }
+        }
+        self.builder.finish_node(); // DefinitionList
+
+        true
+    }
+
+    fn parse_definition_item(&mut self) {
+        self.builder.start_node(SyntaxKind::DefinitionItem.into());
+
+        let line = self.lines[self.pos];
+        let text = line.trim_start();
+        let term = text.trim_end();
+        if text.len() < line.len() {
+            self.builder.token(
+                SyntaxKind::WHITESPACE.into(),
+                &line[..line.len() - text.len()],
+            );
+        }
+        self.builder.start_node(SyntaxKind::Term.into());
+        self.builder.token(SyntaxKind::TEXT.into(), term);
+        self.builder.finish_node(); // Term
+        if term.len() < text.len() {
+            self.builder
+                .token(SyntaxKind::WHITESPACE.into(), &text[term.len()..]);
+        }
+        self.builder.token(SyntaxKind::NEWLINE.into(), "\n");
+        self.pos += 1;
+
+        // Each definition may be preceded by a single blank line
+        loop {
+            let blank = self
+                .lines
+                .get(self.pos)
+                .is_some_and(|line| line.trim().is_empty());
+            let marker_at = if blank { self.pos + 1 } else { self.pos };
+            if !self.starts_definition(marker_at) {
+                break;
+            }
+            if blank {
+                self.try_parse_blank_line();
+            }
+            self.parse_definition(blank);
+        }
+
+        self.builder.finish_node(); // DefinitionItem
+    }
+
+    /// Parse a definition. As in Pandoc, only a loose definition, which
+    /// follows a blank line, may continue lazily.
+    fn parse_definition(&mut self, loose: bool) {
+        let line = self.lines[self.pos];
+        let (marker_start, content_start) = definition_marker(line).unwrap();
+
+        self.builder.start_node(SyntaxKind::Definition.into());
+        if marker_start > 0 {
+            self.builder
+                .token(SyntaxKind::WHITESPACE.into(), &line[..marker_start]);
+        }
+        self.builder.token(
+            SyntaxKind::DefinitionMarker.into(),
+            &line[marker_start..marker_start + 1],
+        );
+        self.builder.token(
+            SyntaxKind::WHITESPACE.into(),
+            &line[marker_start + 1..content_start],
+        );
+
+        // Collect the definition's lines with the continuation indentation
+        // removed
+        let mut definition_lines = vec![&line[content_start..]];
+        let mut end = self.pos + 1;
+        let mut in_paragraph = true;
+
+        while end < self.lines.len() {
+            let line = self.lines[end];
+
+            if line.trim().is_empty() {
+                // Blank lines only belong to the definition if more indented
+                // content follows them
+                let mut next = end;
+                while next < self.lines.len() && self.lines[next].trim().is_empty() {
+                    next += 1;
+                }
+                if next < self.lines.len() && indent_width(self.lines[next]) >= DEFINITION_INDENT {
+                    definition_lines.extend(self.lines[end..next].iter().map(|_| ""));
+                    end = next;
+                    in_paragraph = false;
+                    continue;
+                }
+                break;
+            }
+
+            if indent_width(line) >= DEFINITION_INDENT {
+                let stripped = strip_indent(line, DEFINITION_INDENT);
+                definition_lines.push(stripped);
+                // Deeper indentation does not end an open paragraph
+                in_paragraph = in_paragraph || !stripped.starts_with([' ', '\t']);
+                end += 1;
+                continue;
+            }
+
+            // Lazy continuation of a paragraph
+            if loose
+                && in_paragraph
+                && definition_marker(line).is_none()
+                && !self.starts_definition_item(end)
+                && !self.interrupts_paragraph(end)
+            {
+                definition_lines.push(line.trim_start());
+                end += 1;
+                continue;
+            }
+
+            break;
+        }
+
+        self.parse_nested_lines(definition_lines);
+        self.builder.finish_node(); // Definition
+
+        self.pos = end;
+    }
+}
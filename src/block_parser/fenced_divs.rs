@@ -0,0 +1,122 @@
+use super::{BlockParser, strip_leading_spaces};
+use crate::syntax::SyntaxKind;
+
+/// Count the run of colons at the start of a (space-stripped) line.
+fn get_colon_count(line: &str) -> usize {
+    line.chars().take_while(|&c| c == ':').count()
+}
+
+/// A closing fence is a line of at least three colons and nothing else.
+pub(super) fn is_div_fence_close(line: &str) -> bool {
+    let trimmed = strip_leading_spaces(line).trim_end();
+    get_colon_count(trimmed) >= 3 && trimmed.chars().all(|c| c == ':')
+}
+
+impl BlockParser<'_> {
+    /// Parse a Pandoc fenced div, e.g. `::: {.callout-note}` ... `:::`.
+    ///
+    /// The opening fence needs at least three colons followed by attributes
+    /// or a bare class name, optionally followed by more colons. The div ends
+    /// at the next line consisting only of three or more colons that is not
+    /// consumed by a nested div or code block. An unclosed div extends to the
+    /// end of the enclosing container.
+    pub fn try_parse_fenced_div(&mut self) -> bool {
+        log::debug!("Trying to parse fenced div at position {}", self.pos);
+
+        if self.pos >= self.lines.len() {
+            return false;
+        }
+
+        let line = self.lines[self.pos];
+        let trimmed = strip_leading_spaces(line);
+
+        let colons = get_colon_count(trimmed);
+        if colons < 3 {
+            return false;
+        }
+
+        // Split the rest of the line into the info string and optional
+        // trailing colons
+        let after_marker = &trimmed[colons..];
+        let rest = after_marker.trim_start();
+        let rest_trimmed = rest.trim_end();
+        let info = rest_trimmed.trim_end_matches(':').trim_end();
+        if info.is_empty() {
+            // A bare fence closes a div; it cannot open one
+            return false;
+        }
+        let trailing = rest_trimmed[info.len()..].trim_start();
+
+        self.builder.start_node(SyntaxKind::FencedDiv.into());
+
+        // Opening fence: DivMarker + DivInfo + NEWLINE
+        self.builder.start_node(SyntaxKind::DivFenceOpen.into());
+        let indent = &line[..line.len() - trimmed.len()];
+        if !indent.is_empty() {
+            self.builder.token(SyntaxKind::WHITESPACE.into(), indent);
+        }
+        self.builder
+            .token(SyntaxKind::DivMarker.into(), &trimmed[..colons]);
+        let space = &after_marker[..after_marker.len() - rest.len()];
+        if !space.is_empty() {
+            self.builder.token(SyntaxKind::WHITESPACE.into(), space);
+        }
+        self.builder.start_node(SyntaxKind::DivInfo.into());
+        self.builder.token(SyntaxKind::TEXT.into(), info);
+        self.builder.finish_node(); // DivInfo
+        if !trailing.is_empty() {
+            let space = &rest_trimmed[info.len()..rest_trimmed.len() - trailing.len()];
+            self.builder.token(SyntaxKind::WHITESPACE.into(), space);
+            self.builder.token(SyntaxKind::DivMarker.into(), trailing);
+        }
+        self.builder.token(SyntaxKind::NEWLINE.into(), "\n");
+        self.builder.finish_node(); // DivFenceOpen
+
+        self.pos += 1;
+
+        // Content: parse blocks recursively until the closing fence
+        let outer_start = self.container_start;
+        self.container_start = self.pos;
+        self.div_depth += 1;
+
+        self.builder.start_node(SyntaxKind::DivContent.into());
+        while self.pos < self.lines.len() && !is_div_fence_close(self.lines[self.pos]) {
+            self.parse_block();
+        }
+        self.builder.finish_node(); // DivContent
+
+        self.div_depth -= 1;
+        self.container_start = outer_start;
+
+        // Closing fence (if found)
+        let found_closing = self.pos < self.lines.len();
+        if found_closing {
+            let closing_line = self.lines[self.pos];
+            let closing_trimmed = strip_leading_spaces(closing_line);
+            let closing_marker = closing_trimmed.trim_end();
+
+            self.builder.start_node(SyntaxKind::DivFenceClose.into());
+            let indent = &closing_line[..closing_line.len() - closing_trimmed.len()];
+            if !indent.is_empty() {
+                self.builder.token(SyntaxKind::WHITESPACE.into(), indent);
+            }
+            self.builder
+                .token(SyntaxKind::DivMarker.into(), closing_marker);
+            if closing_marker.len() < closing_trimmed.len() {
+                self.builder.token(
+                    SyntaxKind::WHITESPACE.into(),
+                    &closing_trimmed[closing_marker.len()..],
+                );
+            }
+            self.builder.token(SyntaxKind::NEWLINE.into(), "\n");
+            self.builder.finish_node(); // DivFenceClose
+
+            self.pos += 1;
+        }
+
+        self.builder.finish_node(); // FencedDiv
+
+        log::debug!("Parsed fenced div, found_closing: {}", found_closing);
+        true
+    }
+}
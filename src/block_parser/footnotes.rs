@@ -0,0 +1,121 @@
+use super::BlockParser;
+use super::lists::{indent_width, strip_indent};
+use crate::syntax::SyntaxKind;
+
+/// Continuation lines of a footnote definition are indented by four
+/// columns.
+const FOOTNOTE_INDENT: usize = 4;
+
+/// Length of the `[^label]:` marker at the start of `text`.
+fn footnote_marker_len(text: &str) -> Option<usize> {
+    let label = text.strip_prefix("[^")?;
+    let end = label.find(']')?;
+    if end == 0 || label[..end].contains(|c: char| c.is_whitespace() || c == '[') {
+        return None;
+    }
+    label[end + 1..].starts_with(':').then_some(end + 4)
+}
+
+/// Whether `line` starts a footnote definition, `[^label]: text`.
+pub(super) fn is_footnote_definition_start(line: &str) -> bool {
+    indent_width(line) <= 3 && footnote_marker_len(line.trim_start()).is_some()
+}
+
+impl<'a> BlockParser<'a> {
+    /// Parse a footnote definition:
+    ///
+    /// ```markdown
+    /// [^1]: The note text, which may
+    /// continue lazily or be indented.
+    ///
+    ///     Further paragraphs are indented by four spaces.
+    /// ```
+    pub(super) fn try_parse_footnote_definition(&mut self) -> bool {
+        let line = self.lines[self.pos];
+        if !is_footnote_definition_start(line) {
+            return false;
+        }
+
+        let marker_start = line.len() - line.trim_start().len();
+        let marker_end = marker_start + footnote_marker_len(&line[marker_start..]).unwrap();
+        let rest = &line[marker_end..];
+        let content_start = marker_end + (rest.len() - rest.trim_start().len());
+
+        self.builder
+            .start_node(SyntaxKind::FootnoteDefinition.into());
+        if marker_start > 0 {
+            self.builder
+                .token(SyntaxKind::WHITESPACE.into(), &line[..marker_start]);
+        }
+        self.builder.token(
+            SyntaxKind::FootnoteDefinitionMarker.into(),
+            &line[marker_start..marker_end],
+        );
+        if content_start > marker_end {
+            self.builder.token(
+                SyntaxKind::WHITESPACE.into(),
+                &line[marker_end..content_start],
+            );
+        }
+
+        // Collect the note's lines with the continuation indentation removed
+        let mut note_lines = Vec::new();
+        let first_content = &line[content_start..];
+        if !first_content.is_empty() {
+            note_lines.push(first_content);
+        }
+        let mut end = self.pos + 1;
+        let mut in_paragraph = !first_content.is_empty();
+
+        while end < self.lines.len() {
+            let line = self.lines[end];
+
+            if line.trim().is_empty() {
+                // Blank lines only belong to the note if more indented
+                // content follows them
+                let mut next = end;
+                while next < self.lines.len() && self.lines[next].trim().is_empty() {
+                    next += 1;
+                }
+                if next < self.lines.len() && indent_width(self.lines[next]) >= FOOTNOTE_INDENT {
+                    note_lines.extend(self.lines[end..next].iter().map(|_| ""));
+                    end = next;
+                    in_paragraph = false;
+                    continue;
+                }
+                break;
+            }
+
+            if indent_width(line) >= FOOTNOTE_INDENT {
+                let stripped = strip_indent(line, FOOTNOTE_INDENT);
+                note_lines.push(stripped);
+                // Extra indentation cannot start indented code inside an
+                // open paragraph
+                in_paragraph = in_paragraph || !stripped.starts_with([' ', '\t']);
+                end += 1;
+                continue;
+            }
+
+            // Lazy continuation of a paragraph
+            if in_paragraph
+                && !is_footnote_definition_start(line)
+                && !self.interrupts_paragraph(end)
+            {
+                note_lines.push(line.trim_start());
+                end += 1;
+                continue;
+            }
+
+            break;
+        }
+
+        self.builder.start_node(SyntaxKind::FootnoteContent.into());
+        self.parse_nested_lines(note_lines);
+        self.builder.finish_node(); // FootnoteContent
+
+        self.builder.finish_node(); // FootnoteDefinition
+
+        self.pos = end;
+        true
+    }
+}
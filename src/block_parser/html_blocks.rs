@@ -0,0 +1,240 @@
+use super::{BlockParser, strip_leading_spaces};
+use crate::syntax::SyntaxKind;
+
+/// Tags whose content is raw text, which is kept up to the closing tag even
+/// across blank lines.
+const RAW_TAGS: &[&str] = &["pre", "script", "style", "textarea"];
+
+/// Block-level tags that start an HTML block.
+const BLOCK_TAGS: &[&str] = &[
+    "address",
+    "article",
+    "aside",
+    "blockquote",
+    "body",
+    "canvas",
+    "caption",
+    "center",
+    "col",
+    "colgroup",
+    "dd",
+    "details",
+    "dialog",
+    "div",
+    "dl",
+    "dt",
+    "fieldset",
+    "figcaption",
+    "figure",
+    "footer",
+    "form",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "head",
+    "header",
+    "hgroup",
+    "hr",
+    "html",
+    "iframe",
+    "li",
+    "main",
+    "nav",
+    "noscript",
+    "ol",
+    "p",
+    "section",
+    "summary",
+    "table",
+    "tbody",
+    "td",
+    "tfoot",
+    "th",
+    "thead",
+    "tr",
+    "ul",
+    "video",
+];
+
+/// How an HTML block ends.
+enum HtmlBlockEnd {
+    /// At the line containing `-->`.
+    Comment,
+    /// At the line with the closing tag that balances the opening one. For
+    /// tags other than raw text tags, the block ends at a blank line if the
+    /// tag is never closed.
+    Tag { name: String, raw: bool },
+    /// At the next blank line.
+    BlankLine,
+}
+
+/// The lowercased tag name at the start of `text`, if it is followed by
+/// whitespace, `>`, `/>` or the end of the line.
+fn tag_name(text: &str) -> Option<String> {
+    let len = text
+        .find(|c: char| !c.is_ascii_alphanumeric())
+        .unwrap_or(text.len());
+    let rest = &text[len..];
+    (len > 0
+        && (rest.is_empty()
+            || rest.starts_with(char::is_whitespace)
+            || rest.starts_with('>')
+            || rest.starts_with("/>")))
+    .then(|| text[..len].to_ascii_lowercase())
+}
+
+/// How the HTML block starting at `line` ends, if an HTML block starts
+/// there.
+fn html_block_start(line: &str) -> Option<HtmlBlockEnd> {
+    let text = strip_leading_spaces(line);
+    if text.starts_with("<!--") {
+        return Some(HtmlBlockEnd::Comment);
+    }
+    let rest = text.strip_prefix('<')?;
+    let (closing, rest) = match rest.strip_prefix('/') {
+        Some(rest) => (true, rest),
+        None => (false, rest),
+    };
+    let name = tag_name(rest)?;
+    if RAW_TAGS.contains(&name.as_str()) {
+        (!closing).then_some(HtmlBlockEnd::Tag { name, raw: true })
+    } else if BLOCK_TAGS.contains(&name.as_str()) {
+        if closing {
+            Some(HtmlBlockEnd::BlankLine)
+        } else {
+            Some(HtmlBlockEnd::Tag { name, raw: false })
+        }
+    } else {
+        None
+    }
+}
+
+/// Number of `<name>` tags opened in `line` minus the number closed.
+fn tag_balance(line: &str, name: &str) -> isize {
+    let line = line.to_ascii_lowercase();
+    let count = |prefix: &str| {
+        line.match_indices(prefix)
+            .filter(|&(i, _)| {
+                tag_name(&line[i + prefix.len() - name.len()..]).is_some_and(|n| n == name)
+            })
+            .count() as isize
+    };
+    count(&format!("<{name}")) - count(&format!("</{name}"))
+}
+
+impl BlockParser<'_> {
+    /// Find the last line of the HTML block starting at `start`.
+    fn find_html_block_end(&self, start: usize, end: &HtmlBlockEnd) -> Option<usize> {
+        let first_blank = || {
+            (start..self.lines.len())
+                .find(|&i| self.lines[i].trim().is_empty())
+                .map_or(self.lines.len() - 1, |i| i - 1)
+        };
+        match end {
+            HtmlBlockEnd::Comment => {
+                let first = self.lines[start];
+                let open = first.find("<!--").unwrap() + 4;
+                if first[open..].contains("-->") {
+                    return Some(start);
+                }
+                (start + 1..self.lines.len()).find(|&i| self.lines[i].contains("-->"))
+            }
+            HtmlBlockEnd::Tag { name, raw: true } => {
+                let close = format!("</{name}");
+                (start..self.lines.len())
+                    .find(|&i| self.lines[i].to_ascii_lowercase().contains(&close))
+            }
+            HtmlBlockEnd::Tag { name, raw: false } => {
+                let mut depth = 0;
+                for i in start..self.lines.len() {
+                    depth += tag_balance(self.lines[i], name);
+                    if depth <= 0 {
+                        return Some(i);
+                    }
+                }
+                Some(first_blank())
+            }
+            HtmlBlockEnd::BlankLine => Some(first_blank()),
+        }
+    }
+
+    /// Parse an HTML block, which is kept verbatim:
+    ///
+    /// - an HTML comment, possibly spanning several lines
+    /// - a `<script>`, `<style>`, `<pre>` or `<textarea>` element
+    /// - an element with a block-level tag such as `<div>` or `<details>`,
+    ///   up to its closing tag
+    ///
+    /// Comments become `Comment` nodes, everything else `HtmlBlock` nodes.
+    pub(super) fn try_parse_html_block(&mut self) -> bool {
+        log::debug!("Trying to parse HTML block at position {}", self.pos);
+
+        if self.pos >= self.lines.len() {
+            return false;
+        }
+        let Some(end_kind) = html_block_start(self.lines[self.pos]) else {
+            return false;
+        };
+        let Some(end) = self.find_html_block_end(self.pos, &end_kind) else {
+            return false;
+        };
+
+        log::debug!("Parsing HTML block at lines {}..={}", self.pos + 1, end + 1);
+
+        if let HtmlBlockEnd::Comment = end_kind {
+            self.emit_block_comment(end);
+        } else {
+            self.builder.start_node(SyntaxKind::HtmlBlock.into());
+            for line in &self.lines[self.pos..=end] {
+                if !line.is_empty() {
+                    self.builder.token(SyntaxKind::TEXT.into(), line);
+                }
+                self.builder.token(SyntaxKind::NEWLINE.into(), "\n");
+            }
+            self.builder.finish_node(); // HtmlBlock
+        }
+
+        self.pos = end + 1;
+        true
+    }
+
+    /// Emit the comment spanning the lines up to `end` as a Comment node.
+    /// Anything after the closing `-->` stays in the node as text.
+    fn emit_block_comment(&mut self, end: usize) {
+        let first = self.lines[self.pos];
+        let indent = first.find("<!--").unwrap();
+        let last_close = if end == self.pos {
+            indent + 4 + first[indent + 4..].find("-->").unwrap()
+        } else {
+            self.lines[end].find("-->").unwrap()
+        };
+
+        self.builder.start_node(SyntaxKind::Comment.into());
+        if indent > 0 {
+            self.builder
+                .token(SyntaxKind::WHITESPACE.into(), &first[..indent]);
+        }
+        self.builder.token(SyntaxKind::CommentStart.into(), "<!--");
+        for i in self.pos..=end {
+            let line = self.lines[i];
+            let from = if i == self.pos { indent + 4 } else { 0 };
+            let to = if i == end { last_close } else { line.len() };
+            if from < to {
+                self.builder.token(SyntaxKind::TEXT.into(), &line[from..to]);
+            }
+            if i < end {
+                self.builder.token(SyntaxKind::NEWLINE.into(), "\n");
+            }
+        }
+        self.builder.token(SyntaxKind::CommentEnd.into(), "-->");
+        let after = &self.lines[end][last_close + 3..];
+        if !after.is_empty() {
+            self.builder.token(SyntaxKind::TEXT.into(), after);
+        }
+        self.builder.token(SyntaxKind::NEWLINE.into(), "\n");
+        self.builder.finish_node(); // Comment
+    }
+}
@@ -0,0 +1,153 @@
+use super::{BlockParser, strip_leading_spaces};
+use crate::inline_parser::latex_command_len;
+use crate::syntax::SyntaxKind;
+
+/// The environment name of a `\begin{name}` at the start of `text`.
+fn begin_environment(text: &str) -> Option<&str> {
+    let rest = text.strip_prefix("\\begin{")?;
+    let end = rest.find('}')?;
+    let name = &rest[..end];
+    (!name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '*'))
+        .then_some(name)
+}
+
+/// Number of `\begin{name}` in `line` minus the number of `\end{name}`.
+fn environment_balance(line: &str, name: &str) -> isize {
+    let begins = line.matches(&format!("\\begin{{{name}}}")).count();
+    let ends = line.matches(&format!("\\end{{{name}}}")).count();
+    begins as isize - ends as isize
+}
+
+impl BlockParser<'_> {
+    /// Parse a raw LaTeX environment, kept verbatim:
+    ///
+    /// ```markdown
+    /// \begin{align}
+    ///   a &= b \\
+    ///   c &= d
+    /// \end{align}
+    /// ```
+    ///
+    /// The environment ends at the `\end` matching its `\begin`, so it may
+    /// contain nested environments of the same name as well as blank lines.
+    pub(super) fn try_parse_latex_environment(&mut self) -> bool {
+        log::debug!("Trying to parse LaTeX environment at position {}", self.pos);
+
+        if self.pos >= self.lines.len() {
+            return false;
+        }
+        let first = self.lines[self.pos];
+        let Some(name) = begin_environment(strip_leading_spaces(first)) else {
+            return false;
+        };
+
+        let mut depth = environment_balance(first, name);
+        if depth <= 0 {
+            return false;
+        }
+        let Some(end) = (self.pos + 1..self.lines.len()).find(|&i| {
+            depth += environment_balance(self.lines[i], name);
+            depth <= 0
+        }) else {
+            return false;
+        };
+
+        log::debug!(
+            "Parsing LaTeX environment {} at lines {}..={}",
+            name,
+            self.pos + 1,
+            end + 1
+        );
+
+        self.builder.start_node(SyntaxKind::LatexEnvironment.into());
+
+        self.builder.start_node(SyntaxKind::LatexEnvBegin.into());
+        self.emit_latex_line(first);
+        self.builder.finish_node(); // LatexEnvBegin
+
+        self.builder.start_node(SyntaxKind::LatexEnvContent.into());
+        for line in &self.lines[self.pos + 1..end] {
+            if !line.is_empty() {
+                self.builder.token(SyntaxKind::TEXT.into(), line);
+            }
+            self.builder.token(SyntaxKind::NEWLINE.into(), "\n");
+        }
+        self.builder.finish_node(); // LatexEnvContent
+
+        self.builder.start_node(SyntaxKind::LatexEnvEnd.into());
+        self.emit_latex_line(self.lines[end]);
+        self.builder.finish_node(); // LatexEnvEnd
+
+        self.builder.finish_node(); // LatexEnvironment
+
+        self.pos = end + 1;
+        true
+    }
+
+    /// Parse a block of standalone LaTeX commands, such as `\newpage` or
+    /// `\includegraphics[width=0.5\textwidth]{figure.png}`, that make up
+    /// whole lines. Arguments may span lines.
+    pub(super) fn try_parse_latex_command(&mut self) -> bool {
+        log::debug!("Trying to parse LaTeX command at position {}", self.pos);
+
+        if self.pos >= self.lines.len() {
+            return false;
+        }
+        let first = self.lines[self.pos];
+        let indent = first.len() - strip_leading_spaces(first).len();
+        if !first[indent..].starts_with('\\') {
+            return false;
+        }
+
+        // Arguments cannot span blank lines, so only look as far as the
+        // next one
+        let block_end = (self.pos..self.lines.len())
+            .find(|&i| self.lines[i].trim().is_empty())
+            .unwrap_or(self.lines.len());
+        let text = self.lines[self.pos..block_end].join("\n");
+
+        // One or more commands, ending at the end of a line
+        let mut pos = indent;
+        loop {
+            let Some(len) = latex_command_len(&text[pos..]) else {
+                return false;
+            };
+            pos += len;
+            pos += text[pos..].len() - text[pos..].trim_start_matches([' ', '\t']).len();
+            if pos == text.len() || text[pos..].starts_with('\n') {
+                break;
+            }
+        }
+        let end = self.pos + text[..pos].matches('\n').count();
+
+        log::debug!(
+            "Parsing LaTeX command at lines {}..={}",
+            self.pos + 1,
+            end + 1
+        );
+
+        self.builder.start_node(SyntaxKind::LatexCommand.into());
+        for i in self.pos..=end {
+            self.emit_latex_line(self.lines[i]);
+        }
+        self.builder.finish_node(); // LatexCommand
+
+        self.pos = end + 1;
+        true
+    }
+
+    /// Emit a line as its indentation and text, followed by a newline.
+    fn emit_latex_line(&mut self, line: &str) {
+        let text = line.trim_start();
+        if text.len() < line.len() {
+            self.builder.token(
+                SyntaxKind::WHITESPACE.into(),
+                &line[..line.len() - text.len()],
+            );
+        }
+        if !text.is_empty() {
+            self.builder.token(SyntaxKind::TEXT.into(), text);
+        }
+        self.builder.token(SyntaxKind::NEWLINE.into(), "\n");
+    }
+}
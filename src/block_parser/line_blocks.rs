@@ -0,0 +1,57 @@
+use super::BlockParser;
+use crate::syntax::SyntaxKind;
+
+/// Whether `line` is a line of a line block: a `|` in the first column
+/// followed by a space, or a `|` on its own.
+fn is_line_block_line(line: &str) -> bool {
+    line.strip_prefix('|')
+        .is_some_and(|rest| rest.starts_with(' ') || rest.trim().is_empty())
+}
+
+impl BlockParser<'_> {
+    /// Parse a line block, as used for verse and addresses:
+    ///
+    /// ```markdown
+    /// | The limerick packs laughs anatomical
+    /// |    In space that is quite economical.
+    /// | But the good ones I've seen
+    ///   So seldom are clean
+    /// ```
+    ///
+    /// A line starting with a space continues the previous line. Pipe and
+    /// grid tables take precedence, as they are tried before this.
+    pub(super) fn try_parse_line_block(&mut self) -> bool {
+        if self.pos >= self.lines.len() || !is_line_block_line(self.lines[self.pos]) {
+            return false;
+        }
+
+        log::debug!("Parsing line block at line {}", self.pos + 1);
+
+        self.builder.start_node(SyntaxKind::LineBlock.into());
+        while self.pos < self.lines.len() {
+            let line = self.lines[self.pos];
+            let rest = if is_line_block_line(line) {
+                self.builder.token(SyntaxKind::LineBlockMarker.into(), "|");
+                &line[1..]
+            } else if line.starts_with(' ') && !line.trim().is_empty() {
+                line
+            } else {
+                break;
+            };
+
+            let text = rest.trim_end();
+            if !text.is_empty() {
+                self.builder.token(SyntaxKind::TEXT.into(), text);
+            }
+            if text.len() < rest.len() {
+                self.builder
+                    .token(SyntaxKind::WHITESPACE.into(), &rest[text.len()..]);
+            }
+            self.builder.token(SyntaxKind::NEWLINE.into(), "\n");
+            self.pos += 1;
+        }
+        self.builder.finish_node(); // LineBlock
+
+        true
+    }
+}
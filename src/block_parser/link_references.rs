@@ -0,0 +1,184 @@
+use super::BlockParser;
+use super::attributes::attribute_block_len;
+use crate::inline_parser::{parse_link_label, title_len};
+use crate::syntax::SyntaxKind;
+
+/// A piece of a link reference definition, `[label]: url "title" {.class}`.
+enum ReferencePart<'a> {
+    Label(&'a str),
+    Colon,
+    Url(&'a str),
+    Title(&'a str),
+    Attribute(&'a str),
+    Whitespace(&'a str),
+    Newline,
+}
+
+/// Reads the parts of a link reference definition, which may continue on
+/// the lines after the label.
+struct Reader<'a, 'l> {
+    lines: &'l [&'a str],
+    line: usize,
+    col: usize,
+    parts: Vec<ReferencePart<'a>>,
+}
+
+impl<'a> Reader<'a, '_> {
+    fn rest(&self) -> &'a str {
+        &self.lines[self.line][self.col..]
+    }
+
+    fn push(&mut self, part: ReferencePart<'a>, len: usize) {
+        self.parts.push(part);
+        self.col += len;
+    }
+
+    fn skip_spaces(&mut self) -> usize {
+        let rest = self.rest();
+        let len = rest.len() - rest.trim_start_matches([' ', '\t']).len();
+        if len > 0 {
+            self.push(ReferencePart::Whitespace(&rest[..len]), len);
+        }
+        len
+    }
+
+    /// Skip whitespace, including at most one line break that is not
+    /// followed by a blank line. Returns whether anything was skipped.
+    fn skip_whitespace(&mut self) -> bool {
+        let mut skipped = self.skip_spaces() > 0;
+        if self.rest().is_empty()
+            && let Some(next) = self.lines.get(self.line + 1)
+            && !next.trim().is_empty()
+        {
+            self.parts.push(ReferencePart::Newline);
+            self.line += 1;
+            self.col = 0;
+            self.skip_spaces();
+            skipped = true;
+        }
+        skipped
+    }
+
+    /// Try to read an optional part after whitespace, undoing everything if
+    /// `read` fails.
+    fn optional(&mut self, read: impl FnOnce(&mut Self) -> bool) {
+        let (line, col, count) = (self.line, self.col, self.parts.len());
+        if !(self.skip_whitespace() && read(self)) {
+            self.line = line;
+            self.col = col;
+            self.parts.truncate(count);
+        }
+    }
+}
+
+/// Length of a link destination at the start of `text`: either `<...>` or
+/// a run of non-whitespace characters.
+fn url_len(text: &str) -> Option<usize> {
+    if text.starts_with('<') {
+        return text.find('>').map(|end| end + 1);
+    }
+    let len = text.find(char::is_whitespace).unwrap_or(text.len());
+    (len > 0).then_some(len)
+}
+
+/// Parse a link reference definition starting at the first of `lines`.
+/// Returns its parts and the number of lines it spans.
+fn parse_reference_definition<'a>(lines: &[&'a str]) -> Option<(Vec<ReferencePart<'a>>, usize)> {
+    let line = lines[0];
+    let indent = line.len() - line.trim_start_matches(' ').len();
+    let rest = &line[indent..];
+    if indent > 3 || rest.starts_with("[^") {
+        return None;
+    }
+    let label_len = parse_link_label(rest)?;
+    if rest[1..label_len - 1].trim().is_empty() || !rest[label_len..].starts_with(':') {
+        return None;
+    }
+
+    let mut reader = Reader {
+        lines,
+        line: 0,
+        col: 0,
+        parts: Vec::new(),
+    };
+    reader.skip_spaces();
+    reader.push(ReferencePart::Label(&rest[..label_len]), label_len);
+    reader.push(ReferencePart::Colon, 1);
+
+    // The destination may be on the next line
+    reader.skip_whitespace();
+    if reader.rest().starts_with('[') {
+        return None;
+    }
+    let url = url_len(reader.rest())?;
+    reader.push(ReferencePart::Url(&reader.rest()[..url]), url);
+
+    // The title and attributes are optional, and may each be on the next
+    // line
+    reader.optional(|r| match title_len(r.rest()) {
+        Some(len) => {
+            r.push(ReferencePart::Title(&r.rest()[..len]), len);
+            true
+        }
+        None => false,
+    });
+    reader.optional(|r| match attribute_block_len(r.rest()) {
+        Some(len) => {
+            r.push(ReferencePart::Attribute(&r.rest()[..len]), len);
+            true
+        }
+        None => false,
+    });
+
+    reader.skip_spaces();
+    if !reader.rest().is_empty() {
+        return None;
+    }
+    Some((reader.parts, reader.line + 1))
+}
+
+impl BlockParser<'_> {
+    /// Parse a link reference definition:
+    ///
+    /// ```markdown
+    /// [label]: https://example.com "Title" {.class}
+    /// [other]: <https://example.com/with spaces>
+    ///   'A title on the next line'
+    /// ```
+    ///
+    /// The destination, title and attributes may each start on the line
+    /// after the previous part. Like Pandoc, a definition cannot interrupt a
+    /// paragraph.
+    pub(super) fn try_parse_link_reference_definition(&mut self) -> bool {
+        let Some((parts, line_count)) = parse_reference_definition(&self.lines[self.pos..]) else {
+            return false;
+        };
+
+        log::debug!("Parsing link reference definition at line {}", self.pos + 1);
+
+        self.builder
+            .start_node(SyntaxKind::LinkReferenceDefinition.into());
+        for part in parts {
+            match part {
+                ReferencePart::Label(label) => self
+                    .builder
+                    .token(SyntaxKind::LinkReferenceLabel.into(), label),
+                ReferencePart::Colon => self.builder.token(SyntaxKind::TEXT.into(), ":"),
+                ReferencePart::Url(url) => self.builder.token(SyntaxKind::LinkUrl.into(), url),
+                ReferencePart::Title(title) => {
+                    self.builder.token(SyntaxKind::LinkTitle.into(), title)
+                }
+                ReferencePart::Attribute(attribute) => self.emit_attribute(attribute),
+                ReferencePart::Whitespace(ws) => {
+                    self.builder.token(SyntaxKind::WHITESPACE.into(), ws)
+                }
+                ReferencePart::Newline => self.builder.token(SyntaxKind::NEWLINE.into(), "\n"),
+            }
+        }
+        self.builder.token(SyntaxKind::NEWLINE.into(), "\n");
+        self.builder.finish_node(); // LinkReferenceDefinition
+
+        self.pos += line_count;
+        true
+    }
+}
@@ -0,0 +1,387 @@
+use super::BlockParser;
+use crate::syntax::{SyntaxKind, SyntaxNode};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NumberStyle {
+    Decimal,
+    Hash,
+    Lower, // a. or i.
+    Upper, // A. or I.
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Delimiter {
+    Period,   // 1.
+    OneParen, // 1)
+    TwoParen, // (1)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MarkerKind {
+    Bullet(char),
+    Ordered(NumberStyle, Delimiter),
+}
+
+/// A list marker recognized at the start of a line.
+#[derive(Debug, Clone, Copy)]
+struct ListMarkerMatch {
+    kind: MarkerKind,
+    /// Byte offset where the marker starts (after leading indentation).
+    marker_start: usize,
+    /// Byte offset right after the marker.
+    marker_end: usize,
+    /// Byte offset where the item content starts.
+    content_start: usize,
+    /// Column of the marker.
+    marker_col: usize,
+    /// Column that continuation lines must be indented to.
+    content_col: usize,
+}
+
+/// Width in columns of the leading whitespace of a line (tabs stop at
+/// multiples of four).
+pub(crate) fn indent_width(line: &str) -> usize {
+    let mut col = 0;
+    for c in line.chars() {
+        match c {
+            ' ' => col += 1,
+            '\t' => col += 4 - col % 4,
+            _ => break,
+        }
+    }
+    col
+}
+
+/// Strip up to `cols` columns of leading whitespace from a line.
+pub(crate) fn strip_indent(line: &str, cols: usize) -> &str {
+    let mut col = 0;
+    for (i, c) in line.char_indices() {
+        if col >= cols {
+            return &line[i..];
+        }
+        match c {
+            ' ' => col += 1,
+            '\t' => col += 4 - col % 4,
+            _ => return &line[i..],
+        }
+    }
+    ""
+}
+
+fn parse_enumerator(s: &str) -> Option<(NumberStyle, usize)> {
+    let first = s.chars().next()?;
+    if first.is_ascii_digit() {
+        let len = s.chars().take_while(|c| c.is_ascii_digit()).count();
+        return (len <= 9).then_some((NumberStyle::Decimal, len));
+    }
+    if first == '#' {
+        return Some((NumberStyle::Hash, 1));
+    }
+    let style = if first.is_ascii_lowercase() {
+        NumberStyle::Lower
+    } else if first.is_ascii_uppercase() {
+        NumberStyle::Upper
+    } else {
+        return None;
+    };
+    // A single letter, or a roman numeral
+    let letters = s.chars().take_while(|c| c.is_ascii_alphabetic()).count();
+    if letters == 1 {
+        return Some((style, 1));
+    }
+    let same_case = s[..letters]
+        .chars()
+        .all(|c| c.is_ascii_lowercase() == (style == NumberStyle::Lower));
+    (same_case && roman_numeral_len(&s[..letters]) == letters).then_some((style, letters))
+}
+
+/// Length of the roman numeral at the start of `s`, in either case, read
+/// the way Pandoc does: `M* CM? D? CD? C* XC? L? XL? X* IX? V? IV? I*`.
+fn roman_numeral_len(s: &str) -> usize {
+    let s = s.to_ascii_lowercase();
+    let mut rest = s.as_str();
+    for (part, repeat) in [
+        ("m", true),
+        ("cm", false),
+        ("d", false),
+        ("cd", false),
+        ("c", true),
+        ("xc", false),
+        ("l", false),
+        ("xl", false),
+        ("x", true),
+        ("ix", false),
+        ("v", false),
+        ("iv", false),
+        ("i", true),
+    ] {
+        while let Some(after) = rest.strip_prefix(part) {
+            rest = after;
+            if !repeat {
+                break;
+            }
+        }
+    }
+    s.len() - rest.len()
+}
+
+fn parse_list_marker(line: &str) -> Option<ListMarkerMatch> {
+    let marker_col = indent_width(line);
+    let marker_start = line.len() - line.trim_start_matches([' ', '\t']).len();
+    let rest = &line[marker_start..];
+
+    let (kind, marker_len) = match rest.chars().next()? {
+        c @ ('-' | '+' | '*') => (MarkerKind::Bullet(c), 1),
+        '(' => {
+            let (style, len) = parse_enumerator(&rest[1..])?;
+            if !rest[1 + len..].starts_with(')') {
+                return None;
+            }
+            (MarkerKind::Ordered(style, Delimiter::TwoParen), len + 2)
+        }
+        _ => {
+            let (style, len) = parse_enumerator(rest)?;
+            let delim = match rest[len..].chars().next()? {
+                '.' => Delimiter::Period,
+                ')' => Delimiter::OneParen,
+                _ => return None,
+            };
+            (MarkerKind::Ordered(style, delim), len + 1)
+        }
+    };
+
+    let marker_end = marker_start + marker_len;
+    let after = &line[marker_end..];
+
+    // The marker must be followed by whitespace or the end of the line
+    if !after.is_empty() && !after.starts_with([' ', '\t']) {
+        return None;
+    }
+
+    // As in Pandoc, an uppercase letter followed by a period needs at least
+    // two spaces, so that initials like "B. Russell" don't start a list.
+    // This includes single-letter roman numerals such as "I." and "C."
+    if let MarkerKind::Ordered(NumberStyle::Upper, Delimiter::Period) = kind
+        && marker_len == 2
+        && !after.is_empty()
+        && !after.starts_with("  ")
+    {
+        return None;
+    }
+
+    let marker_end_col = marker_col + (marker_end - marker_start);
+    let content = after.trim_start_matches([' ', '\t']);
+    let spaces = indent_width(after);
+
+    let (content_start, content_col) = if content.is_empty() || spaces > 4 {
+        // Empty item, or the content is an indented code block: the
+        // content column is one space after the marker
+        let start = if after.is_empty() {
+            marker_end
+        } else {
+            marker_end + 1
+        };
+        (start, marker_end_col + 1)
+    } else {
+        (line.len() - content.len(), marker_end_col + spaces)
+    };
+
+    Some(ListMarkerMatch {
+        kind,
+        marker_start,
+        marker_end,
+        content_start,
+        marker_col,
+        content_col,
+    })
+}
+
+/// Whether a line starts with a list marker.
+pub(super) fn is_list_marker_line(line: &str) -> bool {
+    parse_list_marker(line).is_some()
+}
+
+/// A list is loose if any of its items are separated by blank lines, or if
+/// any item contains blocks separated by blank lines.
+pub fn is_loose_list(list: &SyntaxNode) -> bool {
+    let items: Vec<_> = list
+        .children()
+        .filter(|n| n.kind() != SyntaxKind::BlankLine)
+        .collect();
+    if list.children().count() != items.len() {
+        return true;
+    }
+
+    items.iter().any(|item| {
+        let Some(content) = item
+            .children()
+            .find(|n| n.kind() == SyntaxKind::ItemContent)
+        else {
+            return false;
+        };
+        let blocks: Vec<_> = content.children().collect();
+        blocks.iter().enumerate().any(|(i, block)| {
+            block.kind() == SyntaxKind::BlankLine
+                && blocks[i + 1..]
+                    .iter()
+                    .any(|n| n.kind() != SyntaxKind::BlankLine)
+        })
+    })
+}
+
+impl<'a> BlockParser<'a> {
+    /// Parse a bullet or ordered list, including nested lists.
+    ///
+    /// Continuation lines must be indented to the column of the first
+    /// non-space character after the marker. Lazy continuation lines (not
+    /// indented, directly following item text) are also part of the item.
+    /// Item content is parsed recursively as blocks with the indentation
+    /// stripped.
+    pub fn try_parse_list(&mut self) -> bool {
+        log::debug!("Trying to parse list at position {}", self.pos);
+
+        if self.pos >= self.lines.len() {
+            return false;
+        }
+
+        let Some(first) = parse_list_marker(self.lines[self.pos]) else {
+            return false;
+        };
+
+        // Top-level lists may be indented by up to three spaces
+        if first.marker_col > 3 {
+            return false;
+        }
+
+        // A list cannot interrupt a paragraph, except inside list items
+        if self.follows_paragraph() && self.list_depth == 0 {
+            return false;
+        }
+
+        // Lists with different markers may follow each other without a
+        // blank line in between
+        loop {
+            self.parse_list();
+
+            match self.lines.get(self.pos).and_then(|l| parse_list_marker(l)) {
+                Some(m) if m.marker_col <= 3 => continue,
+                _ => break,
+            }
+        }
+
+        log::debug!("Parsed list, ending at position {}", self.pos);
+        true
+    }
+
+    fn parse_list(&mut self) {
+        let first = parse_list_marker(self.lines[self.pos]).unwrap();
+
+        self.builder.start_node(SyntaxKind::List.into());
+
+        loop {
+            let marker = parse_list_marker(self.lines[self.pos]).unwrap();
+            self.parse_list_item(marker);
+
+            // Blank lines between items belong to the list
+            let mut next = self.pos;
+            while next < self.lines.len() && self.lines[next].trim().is_empty() {
+                next += 1;
+            }
+            if next >= self.lines.len() {
+                break;
+            }
+            match parse_list_marker(self.lines[next]) {
+                Some(m) if m.kind == first.kind && m.marker_col < first.content_col => {}
+                _ => break,
+            }
+
+            while self.pos < next {
+                self.try_parse_blank_line();
+            }
+        }
+
+        self.builder.finish_node(); // List
+    }
+
+    fn parse_list_item(&mut self, marker: ListMarkerMatch) {
+        let line = self.lines[self.pos];
+
+        self.builder.start_node(SyntaxKind::ListItem.into());
+
+        if marker.marker_start > 0 {
+            self.builder
+                .token(SyntaxKind::WHITESPACE.into(), &line[..marker.marker_start]);
+        }
+        self.builder.token(
+            SyntaxKind::ListMarker.into(),
+            &line[marker.marker_start..marker.marker_end],
+        );
+        if marker.content_start > marker.marker_end {
+            self.builder.token(
+                SyntaxKind::WHITESPACE.into(),
+                &line[marker.marker_end..marker.content_start],
+            );
+        }
+
+        // Collect the item's lines with the list indentation removed. The
+        // removed indentation is not kept in the tree, and blank lines are
+        // passed on empty, so a ListItem's text is not the source text: the
+        // formatter rebuilds the indentation from the marker instead
+        let mut item_lines = Vec::new();
+        let first_content = &line[marker.content_start..];
+        if !first_content.trim().is_empty() {
+            item_lines.push(first_content);
+        }
+        let mut end = self.pos + 1;
+        let mut in_paragraph = !first_content.trim().is_empty();
+
+        while end < self.lines.len() {
+            let line = self.lines[end];
+
+            if line.trim().is_empty() {
+                // Blank lines only belong to the item if more item content
+                // follows them
+                let mut next = end;
+                while next < self.lines.len() && self.lines[next].trim().is_empty() {
+                    next += 1;
+                }
+                if next < self.lines.len() && indent_width(self.lines[next]) >= marker.content_col {
+                    item_lines.extend(self.lines[end..next].iter().map(|_| ""));
+                    end = next;
+                    in_paragraph = false;
+                    continue;
+                }
+                break;
+            }
+
+            if indent_width(line) >= marker.content_col {
+                let stripped = strip_indent(line, marker.content_col);
+                item_lines.push(stripped);
+                // A line indented past the content column continues an open
+                // paragraph rather than starting indented code
+                in_paragraph = in_paragraph || !stripped.starts_with([' ', '\t']);
+                end += 1;
+                continue;
+            }
+
+            // Lazy continuation of a paragraph
+            if in_paragraph && !is_list_marker_line(line) && !self.interrupts_paragraph(end) {
+                item_lines.push(line.trim_start());
+                end += 1;
+                continue;
+            }
+
+            break;
+        }
+
+        self.builder.start_node(SyntaxKind::ItemContent.into());
+        self.list_depth += 1;
+        self.parse_nested_lines(item_lines);
+        self.list_depth -= 1;
+        self.builder.finish_node(); // ItemContent
+
+        self.builder.finish_node(); // ListItem
+
+        self.pos = end;
+    }
+}
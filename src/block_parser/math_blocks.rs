@@ -0,0 +1,127 @@
+use super::attributes::split_trailing_attribute;
+use super::{BlockParser, strip_leading_spaces};
+use crate::syntax::SyntaxKind;
+
+/// Split a line ending in the closing `$$` into the text up to and
+/// including the marker and, if present, the whitespace and attribute block
+/// such as `{#eq-label}` after it.
+fn split_closing_attribute(line: &str) -> (&str, Option<(&str, &str)>) {
+    let trimmed = line.trim_end();
+    match split_trailing_attribute(trimmed) {
+        Some((before, whitespace, attribute)) if before.ends_with("$$") => {
+            (before, Some((whitespace, attribute)))
+        }
+        _ => (trimmed, None),
+    }
+}
+
+impl BlockParser<'_> {
+    /// Find the line that closes a display math block opened at `start`.
+    ///
+    /// The block opens with `$$` at the start of a line and closes at the
+    /// first line ending in `$$` (optionally followed by an attribute block).
+    /// Display math cannot contain blank lines.
+    pub(super) fn find_math_block_end(&self, start: usize) -> Option<usize> {
+        let opening = strip_leading_spaces(self.lines[start]).strip_prefix("$$")?;
+
+        // Single-line form: $$ x = y $$
+        let (before, _) = split_closing_attribute(opening);
+        if before.len() >= 2 && before.ends_with("$$") {
+            return Some(start);
+        }
+
+        for i in start + 1..self.lines.len() {
+            let line = self.lines[i];
+            if line.trim().is_empty() {
+                return None;
+            }
+            let (before, _) = split_closing_attribute(line);
+            if before.ends_with("$$") {
+                return Some(i);
+            }
+        }
+        None
+    }
+
+    /// Parse a display math block (`$$ ... $$`), with an optional attribute
+    /// block such as `{#eq-label}` after the closing `$$`.
+    pub fn try_parse_math_block(&mut self) -> bool {
+        log::debug!("Trying to parse math block at position {}", self.pos);
+
+        if self.pos >= self.lines.len() {
+            return false;
+        }
+
+        let Some(end) = self.find_math_block_end(self.pos) else {
+            return false;
+        };
+
+        self.builder.start_node(SyntaxKind::MathBlock.into());
+
+        // Opening marker, with any content that follows it on the same line
+        let line = self.lines[self.pos];
+        let trimmed = strip_leading_spaces(line);
+        if trimmed.len() < line.len() {
+            self.builder.token(
+                SyntaxKind::WHITESPACE.into(),
+                &line[..line.len() - trimmed.len()],
+            );
+        }
+        self.builder.token(SyntaxKind::BlockMathMarker.into(), "$$");
+
+        let mut content: Vec<&str> = Vec::new();
+        let closing_line = if end == self.pos {
+            &trimmed[2..]
+        } else {
+            content.push(&trimmed[2..]);
+            content.extend(&self.lines[self.pos + 1..end]);
+            self.lines[end]
+        };
+
+        // Text before the closing marker on the last line
+        let (before, attribute) = split_closing_attribute(closing_line);
+        content.push(&before[..before.len() - 2]);
+
+        // Markers on their own lines are separated from the content by
+        // newlines rather than being part of it
+        let first = content.iter().position(|c| !c.trim().is_empty());
+        let last = content.iter().rposition(|c| !c.trim().is_empty());
+
+        if end > self.pos && first != Some(0) {
+            self.builder.token(SyntaxKind::NEWLINE.into(), "\n");
+        }
+
+        if let (Some(first), Some(last)) = (first, last) {
+            self.builder.start_node(SyntaxKind::MathContent.into());
+            for (i, piece) in content[first..=last].iter().enumerate() {
+                if i > 0 {
+                    self.builder.token(SyntaxKind::NEWLINE.into(), "\n");
+                }
+                self.builder.token(SyntaxKind::TEXT.into(), piece);
+            }
+            self.builder.finish_node(); // MathContent
+
+            if end > self.pos && last + 1 < content.len() {
+                self.builder.token(SyntaxKind::NEWLINE.into(), "\n");
+            }
+        }
+
+        self.builder.token(SyntaxKind::BlockMathMarker.into(), "$$");
+
+        if let Some((whitespace, attribute)) = attribute {
+            if !whitespace.is_empty() {
+                self.builder
+                    .token(SyntaxKind::WHITESPACE.into(), whitespace);
+            }
+            self.emit_attribute(attribute);
+        }
+        self.builder.token(SyntaxKind::NEWLINE.into(), "\n");
+
+        self.builder.finish_node(); // MathBlock
+
+        log::debug!("Parsed math block at lines {}..={}", self.pos + 1, end + 1);
+
+        self.pos = end + 1;
+        true
+    }
+}
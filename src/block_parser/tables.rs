@@ -0,0 +1,549 @@
+use super::BlockParser;
+use crate::syntax::SyntaxKind;
+
+/// A piece of a pipe table row.
+enum RowPart<'a> {
+    Pipe,
+    Cell(&'a str),
+}
+
+/// Byte offsets of the unescaped `|` characters in `line` that are not
+/// inside a code span.
+fn pipe_positions(line: &str) -> Vec<usize> {
+    let mut positions = Vec::new();
+    let mut escaped = false;
+    let mut i = 0;
+    while let Some(c) = line[i..].chars().next() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '|' => positions.push(i),
+            '`' => {
+                // Skip to the end of a code span, or over the whole backtick
+                // run if it is unmatched
+                let run = line[i..].len() - line[i..].trim_start_matches('`').len();
+                i += run;
+                if let Some(len) = closing_backticks(&line[i..], run) {
+                    i += len;
+                }
+                continue;
+            }
+            _ => {}
+        }
+        i += c.len_utf8();
+    }
+    positions
+}
+
+/// The length of `text` up to and including the first run of exactly `run`
+/// backticks.
+fn closing_backticks(text: &str, run: usize) -> Option<usize> {
+    let mut i = 0;
+    while let Some(start) = text[i..].find('`').map(|offset| i + offset) {
+        let len = text[start..].len() - text[start..].trim_start_matches('`').len();
+        if len == run {
+            return Some(start + len);
+        }
+        i = start + len;
+    }
+    None
+}
+
+/// Split the content of a row (without surrounding whitespace) into pipes
+/// and cells. Leading and trailing pipes are optional.
+fn split_row(row: &str) -> Vec<RowPart<'_>> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+    for pipe in pipe_positions(row) {
+        if pipe > 0 {
+            parts.push(RowPart::Cell(&row[start..pipe]));
+        }
+        parts.push(RowPart::Pipe);
+        start = pipe + 1;
+    }
+    if start < row.len() {
+        parts.push(RowPart::Cell(&row[start..]));
+    }
+    parts
+}
+
+fn row_cells(line: &str) -> Vec<&str> {
+    split_row(line.trim())
+        .into_iter()
+        .filter_map(|part| match part {
+            RowPart::Cell(cell) => Some(cell),
+            RowPart::Pipe => None,
+        })
+        .collect()
+}
+
+fn is_pipe_row(line: &str) -> bool {
+    !line.trim().is_empty() && !pipe_positions(line).is_empty()
+}
+
+/// Whether `line` is the alignment row of a pipe table, e.g.
+/// `|:---|---:|:---:|`.
+fn is_alignment_row(line: &str) -> bool {
+    is_pipe_row(line)
+        && row_cells(line).iter().all(|cell| {
+            let cell = cell.trim();
+            let dashes = cell.strip_prefix(':').unwrap_or(cell);
+            let dashes = dashes.strip_suffix(':').unwrap_or(dashes);
+            !dashes.is_empty() && dashes.chars().all(|c| c == '-')
+        })
+}
+
+/// Whether `line` is a grid table border such as `+---+:==+`.
+fn is_grid_border(line: &str) -> bool {
+    let line = line.trim_end();
+    line.len() >= 3
+        && line.starts_with('+')
+        && line.ends_with('+')
+        && line.contains(['-', '='])
+        && line.chars().all(|c| matches!(c, '+' | '-' | '=' | ':'))
+}
+
+/// Column ranges of a dash line such as `-------  ------ ----------`, i.e.
+/// the runs of dashes in a line consisting only of dashes and spaces.
+fn dash_columns(line: &str) -> Option<Vec<(usize, usize)>> {
+    let line = line.trim_end();
+    if !line.contains('-') || !line.chars().all(|c| c == '-' || c == ' ') {
+        return None;
+    }
+    let mut columns = Vec::new();
+    let mut start = None;
+    for (i, c) in line.char_indices() {
+        match (c, start) {
+            ('-', None) => start = Some(i),
+            (' ', Some(s)) => {
+                columns.push((s, i));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(s) = start {
+        columns.push((s, line.len()));
+    }
+    Some(columns)
+}
+
+fn is_dash_line(line: &str) -> bool {
+    dash_columns(line).is_some()
+}
+
+/// Whether `line` is a single unbroken run of at least three dashes, as
+/// used to open a multiline table.
+fn is_solid_dash_line(line: &str) -> bool {
+    dash_columns(line).is_some_and(|columns| columns.len() == 1 && columns[0].1 - columns[0].0 >= 3)
+}
+
+/// Length of the caption marker (`:` or `Table:`) at the start of a
+/// trimmed line, if the line is a table caption.
+fn caption_marker_len(line: &str) -> Option<usize> {
+    let len = if line.starts_with("Table:") {
+        6
+    } else if line.starts_with(':') {
+        1
+    } else {
+        return None;
+    };
+    line[len..].starts_with(char::is_whitespace).then_some(len)
+}
+
+/// Split a trailing attribute block such as `{#tbl-id}` off a caption.
+fn split_caption_attribute(text: &str) -> (&str, Option<&str>) {
+    if text.ends_with('}')
+        && let Some(open) = text.rfind('{')
+        && (open == 0 || text[..open].ends_with(char::is_whitespace))
+    {
+        return (text[..open].trim_end(), Some(&text[open..]));
+    }
+    (text, None)
+}
+
+impl BlockParser<'_> {
+    /// Parse a pipe table:
+    ///
+    /// ```markdown
+    /// | Right | Left | Center |
+    /// |------:|:-----|:------:|
+    /// |    12 | 12   |   12   |
+    ///
+    /// : Caption {#tbl-id}
+    /// ```
+    pub(super) fn try_parse_pipe_table(&mut self) -> bool {
+        if !self.has_blank_line_before() || self.pos + 1 >= self.lines.len() {
+            return false;
+        }
+
+        let header = self.lines[self.pos];
+        let alignment = self.lines[self.pos + 1];
+        if !is_pipe_row(header)
+            || !is_alignment_row(alignment)
+            || row_cells(header).len() != row_cells(alignment).len()
+        {
+            return false;
+        }
+
+        log::debug!("Parsing pipe table at line {}", self.pos + 1);
+
+        self.builder.start_node(SyntaxKind::PipeTable.into());
+
+        self.builder.start_node(SyntaxKind::TableHeader.into());
+        self.emit_table_row(header, SyntaxKind::TableCell);
+        self.builder.finish_node(); // TableHeader
+
+        self.builder.start_node(SyntaxKind::TableSeparator.into());
+        self.emit_table_row(alignment, SyntaxKind::TableAlignment);
+        self.builder.finish_node(); // TableSeparator
+
+        self.pos += 2;
+        while self.pos < self.lines.len() && is_pipe_row(self.lines[self.pos]) {
+            self.emit_table_row(self.lines[self.pos], SyntaxKind::TableCell);
+            self.pos += 1;
+        }
+
+        self.try_parse_table_caption();
+
+        self.builder.finish_node(); // PipeTable
+        true
+    }
+
+    /// Parse a grid table, which starts and ends with a border line:
+    ///
+    /// ```markdown
+    /// +---------+--------------------+
+    /// | Fruit   | Advantages         |
+    /// +=========+====================+
+    /// | Bananas | - built-in wrapper |
+    /// |         | - bright color     |
+    /// +---------+--------------------+
+    /// ```
+    ///
+    /// Cells are kept verbatim, line by line. Next to a cell spanning rows,
+    /// a border only spans some columns, as in `+---+   |`; such lines are
+    /// rows as well.
+    pub(super) fn try_parse_grid_table(&mut self) -> bool {
+        let first = self.lines[self.pos];
+        let indent = first.len() - first.trim_start().len();
+        if indent > 3 || !is_grid_border(&first[indent..]) {
+            return false;
+        }
+
+        // The table extends over all following lines that start with `+`
+        // or `|` at the same indentation, up to the last border
+        let mut last_border = self.pos;
+        let mut end = self.pos + 1;
+        while end < self.lines.len() {
+            let line = self.lines[end];
+            let Some(rest) = line
+                .get(indent..)
+                .filter(|_| line[..indent].trim().is_empty())
+            else {
+                break;
+            };
+            if is_grid_border(rest) {
+                last_border = end;
+            } else if !rest.starts_with(['|', '+']) {
+                break;
+            }
+            end += 1;
+        }
+        if last_border == self.pos {
+            return false;
+        }
+
+        log::debug!("Parsing grid table at line {}", self.pos + 1);
+
+        self.builder.start_node(SyntaxKind::GridTable.into());
+        for i in self.pos..=last_border {
+            let line = self.lines[i];
+            let kind = if is_grid_border(&line[indent..]) {
+                SyntaxKind::GridTableBorder
+            } else {
+                SyntaxKind::GridTableRow
+            };
+            self.builder.start_node(kind.into());
+            self.emit_verbatim_line(line);
+            self.builder.finish_node();
+        }
+        self.pos = last_border + 1;
+
+        self.try_parse_table_caption();
+
+        self.builder.finish_node(); // GridTable
+        true
+    }
+
+    /// Parse a simple or multiline table, both of which are laid out with
+    /// dash lines. Column alignment follows from the position of the header
+    /// text relative to the dashes below it, so the lines are kept verbatim.
+    ///
+    /// A simple table has a header line followed by a dash line with one
+    /// run of dashes per column, and ends at a blank line:
+    ///
+    /// ```markdown
+    ///   Right     Left     Center     Default
+    /// -------     ------ ----------   -------
+    ///      12     12        12            12
+    /// ```
+    ///
+    /// Without a header, the table opens and closes with a dash line. A
+    /// multiline table opens and closes with a solid dash line, and its rows
+    /// may span several lines, separated by blank lines:
+    ///
+    /// ```markdown
+    /// ---------------------------------
+    ///  Centered   Left
+    ///   Header    Aligned
+    /// ----------- ---------------------
+    ///    First    Example of a row that
+    ///             spans multiple lines.
+    ///
+    ///   Second    Another row.
+    /// ---------------------------------
+    /// ```
+    ///
+    /// A single line underlined by one run of dashes is a setext heading,
+    /// and a dash line on its own is a thematic break, not a table.
+    pub(super) fn try_parse_dash_table(&mut self) -> bool {
+        if self.pos >= self.lines.len() || !self.has_blank_line_before() {
+            return false;
+        }
+        let lines = &self.lines[self.pos..];
+        let is_blank = |i: usize| lines.get(i).is_none_or(|line| line.trim().is_empty());
+
+        if !is_dash_line(lines[0]) {
+            // Simple table with a header: the dash line must have more than
+            // one column to tell it apart from a setext heading underline
+            if lines.len() < 2 || dash_columns(lines[1]).is_none_or(|columns| columns.len() < 2) {
+                return false;
+            }
+            let mut end = 2;
+            while !is_blank(end) && !is_dash_line(lines[end]) {
+                end += 1;
+            }
+            let closed = !is_blank(end);
+            if closed && !is_blank(end + 1) {
+                return false;
+            }
+            log::debug!("Parsing simple table at line {}", self.pos + 1);
+            self.builder.start_node(SyntaxKind::SimpleTable.into());
+            self.emit_table_lines(SyntaxKind::TableHeader, 1);
+            self.emit_table_lines(SyntaxKind::TableSeparator, 1);
+            for _ in 2..end {
+                self.emit_table_lines(SyntaxKind::TableRow, 1);
+            }
+            if closed {
+                self.emit_table_lines(SyntaxKind::TableSeparator, 1);
+            }
+            self.try_parse_table_caption();
+            self.builder.finish_node(); // SimpleTable
+            return true;
+        }
+
+        // Starting with a dash line, find the next one. Rows may be
+        // separated by single blank lines, but the table may not start with
+        // a blank line.
+        let find_dash_line = |start: usize| -> Option<(usize, bool)> {
+            let mut blank_seen = false;
+            let mut i = start;
+            loop {
+                if i >= lines.len() || (is_blank(i) && (i == start || is_blank(i + 1))) {
+                    return None;
+                }
+                if is_dash_line(lines[i]) {
+                    return Some((i, blank_seen));
+                }
+                blank_seen |= is_blank(i);
+                i += 1;
+            }
+        };
+        let Some((first_end, blank_seen)) = find_dash_line(1).filter(|&(end, _)| end > 1) else {
+            return false;
+        };
+
+        if is_blank(first_end + 1) {
+            // Headerless table
+            let kind = if blank_seen {
+                SyntaxKind::MultilineTable
+            } else {
+                SyntaxKind::SimpleTable
+            };
+            log::debug!("Parsing headerless {:?} at line {}", kind, self.pos + 1);
+            self.builder.start_node(kind.into());
+            self.emit_table_lines(SyntaxKind::TableSeparator, 1);
+            if blank_seen {
+                self.emit_multiline_rows(first_end - 1);
+            } else {
+                for _ in 1..first_end {
+                    self.emit_table_lines(SyntaxKind::TableRow, 1);
+                }
+            }
+            self.emit_table_lines(SyntaxKind::TableSeparator, 1);
+        } else {
+            // Multiline table with a header, between the opening solid
+            // line and the dash line marking the columns
+            if blank_seen || !is_solid_dash_line(lines[0]) {
+                return false;
+            }
+            let Some((end, _)) = find_dash_line(first_end + 1) else {
+                return false;
+            };
+            if !is_blank(end + 1) {
+                return false;
+            }
+            log::debug!("Parsing multiline table at line {}", self.pos + 1);
+            self.builder.start_node(SyntaxKind::MultilineTable.into());
+            self.emit_table_lines(SyntaxKind::TableSeparator, 1);
+            self.emit_table_lines(SyntaxKind::TableHeader, first_end - 1);
+            self.emit_table_lines(SyntaxKind::TableSeparator, 1);
+            self.emit_multiline_rows(end - first_end - 1);
+            self.emit_table_lines(SyntaxKind::TableSeparator, 1);
+        }
+        self.try_parse_table_caption();
+        self.builder.finish_node();
+        true
+    }
+
+    /// Emit the next `count` lines verbatim in a node of the given kind.
+    fn emit_table_lines(&mut self, kind: SyntaxKind, count: usize) {
+        self.builder.start_node(kind.into());
+        for _ in 0..count {
+            self.emit_verbatim_line(self.lines[self.pos]);
+            self.pos += 1;
+        }
+        self.builder.finish_node();
+    }
+
+    /// Emit the next `count` lines as the rows of a multiline table, each
+    /// row running up to the next blank line.
+    fn emit_multiline_rows(&mut self, count: usize) {
+        let end = self.pos + count;
+        while self.pos < end {
+            if !self.try_parse_blank_line() {
+                let mut len = 1;
+                while self.pos + len < end && !self.lines[self.pos + len].trim().is_empty() {
+                    len += 1;
+                }
+                self.emit_table_lines(SyntaxKind::TableRow, len);
+            }
+        }
+    }
+
+    /// Emit `line` as its indentation and text, followed by a newline.
+    fn emit_verbatim_line(&mut self, line: &str) {
+        let indent = line.len() - line.trim_start().len();
+        if indent > 0 {
+            self.builder
+                .token(SyntaxKind::WHITESPACE.into(), &line[..indent]);
+        }
+        if indent < line.len() {
+            self.builder.token(SyntaxKind::TEXT.into(), &line[indent..]);
+        }
+        self.builder.token(SyntaxKind::NEWLINE.into(), "\n");
+    }
+
+    /// Emit a TableRow node for `line`, with each cell in a node of kind
+    /// `cell_kind`.
+    fn emit_table_row(&mut self, line: &str, cell_kind: SyntaxKind) {
+        let content = line.trim();
+        let leading = &line[..line.len() - line.trim_start().len()];
+        let trailing = &line[leading.len() + content.len()..];
+
+        self.builder.start_node(SyntaxKind::TableRow.into());
+        if !leading.is_empty() {
+            self.builder.token(SyntaxKind::WHITESPACE.into(), leading);
+        }
+        for part in split_row(content) {
+            match part {
+                RowPart::Pipe => self.builder.token(SyntaxKind::TablePipe.into(), "|"),
+                RowPart::Cell(cell) => {
+                    self.builder.start_node(cell_kind.into());
+                    if !cell.is_empty() {
+                        self.builder.token(SyntaxKind::TEXT.into(), cell);
+                    }
+                    self.builder.finish_node();
+                }
+            }
+        }
+        if !trailing.is_empty() {
+            self.builder.token(SyntaxKind::WHITESPACE.into(), trailing);
+        }
+        self.builder.token(SyntaxKind::NEWLINE.into(), "\n");
+        self.builder.finish_node(); // TableRow
+    }
+
+    /// Parse a caption (`: Caption {#tbl-id}`) following a table, possibly
+    /// after a blank line. The caption continues until the next blank line.
+    fn try_parse_table_caption(&mut self) {
+        let mut start = self.pos;
+        if start < self.lines.len() && self.lines[start].trim().is_empty() {
+            start += 1;
+        }
+        let Some(first) = self.lines.get(start) else {
+            return;
+        };
+        let Some(marker_len) = caption_marker_len(first.trim_start()) else {
+            return;
+        };
+
+        if start > self.pos {
+            self.try_parse_blank_line();
+        }
+
+        let mut end = start + 1;
+        while end < self.lines.len() && !self.lines[end].trim().is_empty() {
+            end += 1;
+        }
+
+        self.builder.start_node(SyntaxKind::TableCaption.into());
+        for i in start..end {
+            let line = self.lines[i];
+            let content = line.trim();
+            let leading = &line[..line.len() - line.trim_start().len()];
+            if !leading.is_empty() {
+                self.builder.token(SyntaxKind::WHITESPACE.into(), leading);
+            }
+
+            let mut text = content;
+            if i == start {
+                self.builder
+                    .token(SyntaxKind::TableCaptionMarker.into(), &text[..marker_len]);
+                let rest = &text[marker_len..];
+                let ws = rest.len() - rest.trim_start().len();
+                self.builder
+                    .token(SyntaxKind::WHITESPACE.into(), &rest[..ws]);
+                text = &rest[ws..];
+            }
+
+            let (before, attribute) = if i + 1 == end {
+                split_caption_attribute(text)
+            } else {
+                (text, None)
+            };
+            if !before.is_empty() {
+                self.builder.token(SyntaxKind::TEXT.into(), before);
+            }
+            if let Some(attribute) = attribute {
+                let ws = &text[before.len()..text.len() - attribute.len()];
+                if !ws.is_empty() {
+                    self.builder.token(SyntaxKind::WHITESPACE.into(), ws);
+                }
+                self.builder.start_node(SyntaxKind::Attribute.into());
+                self.builder.token(SyntaxKind::TEXT.into(), attribute);
+                self.builder.finish_node();
+            }
+
+            let trailing = &line[leading.len() + content.len()..];
+            if !trailing.is_empty() {
+                self.builder.token(SyntaxKind::WHITESPACE.into(), trailing);
+            }
+            self.builder.token(SyntaxKind::NEWLINE.into(), "\n");
+        }
+        self.builder.finish_node(); // TableCaption
+
+        self.pos = end;
+    }
+}
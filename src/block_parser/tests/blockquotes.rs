@@ -166,6 +166,20 @@ fn blockquote_after_blank_line() {
     assert_eq!(count_nodes_of_type(&tree, SyntaxKind::PARAGRAPH), 2);
 }
 
+#[test]
+fn blockquote_may_follow_other_blocks_directly() {
+    for input in [
+        "---\n> Quoted",
+        "# Heading\n> Quoted",
+        "```\ncode\n```\n> Quoted",
+    ] {
+        let tree = BlockParser::new(input).parse();
+
+        // Only a paragraph needs a blank line to end before a blockquote
+        assert_eq!(count_nodes_of_type(&tree, SyntaxKind::BlockQuote), 1);
+    }
+}
+
 #[test]
 fn complex_nested_structure() {
     let input = "> Outer quote with paragraph\n>\n> > Inner quote\n> >\n> > > Triple nested\n> >\n> > Back to double nested\n>\n> Back to outer";
@@ -220,7 +234,7 @@ fn spec_nested_blockquote() {
     let outer = &blockquotes[0];
 
     // Inner blockquote should be nested inside outer
-    assert!(find_nodes_of_type(outer, SyntaxKind::BlockQuote).len() > 0);
+    assert!(!find_nodes_of_type(outer, SyntaxKind::BlockQuote).is_empty());
 }
 
 #[test]
@@ -277,7 +291,7 @@ fn spec_blockquote_optional_space_after_marker() {
 fn spec_blockquote_max_three_space_indent() {
     // Up to 3 spaces before > should be allowed
     let input1 = "   > Three spaces should work";
-    let input2 = "    > Four spaces should not work"; // This should be treated as code block or regular paragraph
+    let input2 = "    > Four spaces should not work"; // This is a code block
 
     let parser1 = BlockParser::new(input1);
     let tree1 = parser1.parse();
@@ -288,9 +302,12 @@ fn spec_blockquote_max_three_space_indent() {
     // First should create blockquote
     assert_eq!(count_nodes_of_type(&tree1, SyntaxKind::BlockQuote), 1);
 
-    // Second should NOT create blockquote (should be treated as regular paragraph)
+    // Second should NOT create blockquote (it is an indented code block)
     assert_eq!(count_nodes_of_type(&tree2, SyntaxKind::BlockQuote), 0);
-    assert_eq!(count_nodes_of_type(&tree2, SyntaxKind::PARAGRAPH), 1);
+    assert_eq!(
+        count_nodes_of_type(&tree2, SyntaxKind::IndentedCodeBlock),
+        1
+    );
 }
 
 // Test lazy blockquote form
@@ -318,3 +335,82 @@ fn spec_lazy_blockquote_form() {
         "Should contain lazy continuation"
     );
 }
+
+#[test]
+fn blockquote_starting_with_code_block() {
+    let input = "> ```r\n> x <- 1\n> ```\n>\n> After the code.\n";
+    let tree = BlockParser::new(input).parse();
+
+    let blockquotes = find_nodes_of_type(&tree, SyntaxKind::BlockQuote);
+    assert_eq!(blockquotes.len(), 1);
+    let code = find_nodes_of_type(&blockquotes[0], SyntaxKind::CodeBlock);
+    assert_eq!(code.len(), 1);
+    assert_eq!(code[0].text().to_string(), "```r\nx <- 1\n```\n");
+    assert_eq!(
+        count_nodes_of_type(&blockquotes[0], SyntaxKind::PARAGRAPH),
+        1
+    );
+}
+
+#[test]
+fn blockquote_with_list() {
+    let input = "> 1. First\n> 2. Second\n";
+    let tree = BlockParser::new(input).parse();
+
+    let blockquotes = find_nodes_of_type(&tree, SyntaxKind::BlockQuote);
+    assert_eq!(blockquotes.len(), 1);
+    assert_eq!(count_nodes_of_type(&blockquotes[0], SyntaxKind::List), 1);
+    assert_eq!(
+        count_nodes_of_type(&blockquotes[0], SyntaxKind::ListItem),
+        2
+    );
+}
+
+#[test]
+fn blockquote_inside_list_item() {
+    let input = "- Item\n\n  > Quoted\n  > text\n";
+    let tree = BlockParser::new(input).parse();
+
+    let items = find_nodes_of_type(&tree, SyntaxKind::ListItem);
+    assert_eq!(items.len(), 1);
+    assert_eq!(count_nodes_of_type(&items[0], SyntaxKind::BlockQuote), 1);
+}
+
+#[test]
+fn blockquote_marker_is_a_token() {
+    let input = "  > Quoted\n";
+    let tree = BlockParser::new(input).parse();
+
+    let blockquote = &find_nodes_of_type(&tree, SyntaxKind::BlockQuote)[0];
+    let tokens: Vec<_> = blockquote
+        .children_with_tokens()
+        .filter_map(|el| el.into_token())
+        .map(|t| (t.kind(), t.text().to_string()))
+        .collect();
+    assert_eq!(
+        tokens,
+        &[
+            (SyntaxKind::WHITESPACE, "  ".to_string()),
+            (SyntaxKind::BlockQuoteMarker, ">".to_string()),
+            (SyntaxKind::WHITESPACE, " ".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn blank_line_separates_blockquotes() {
+    let input = "> One\n\n> Two\n";
+    let tree = BlockParser::new(input).parse();
+
+    assert_eq!(count_nodes_of_type(&tree, SyntaxKind::BlockQuote), 2);
+}
+
+#[test]
+fn lazy_line_does_not_follow_blank_quoted_line() {
+    let input = "> Quoted\n>\nNot quoted\n";
+    let tree = BlockParser::new(input).parse();
+
+    let blockquote = &find_nodes_of_type(&tree, SyntaxKind::BlockQuote)[0];
+    assert!(!blockquote.text().to_string().contains("Not quoted"));
+    assert_eq!(count_nodes_of_type(&tree, SyntaxKind::PARAGRAPH), 2);
+}
@@ -61,7 +61,8 @@ fn parses_code_block_with_attributes() {
     let input = "```{python}\nprint(\"hello\")\n```\n";
     let node = parse_blocks(input);
 
-    assert_block_kinds(input, &[SyntaxKind::CodeBlock]);
+    // An executable cell keeps the whole info string
+    assert_block_kinds(input, &[SyntaxKind::CodeCell]);
 
     let content = get_code_content(&node).unwrap();
     assert_eq!(content, "print(\"hello\")");
@@ -75,7 +76,8 @@ fn parses_code_block_with_complex_attributes() {
     let input = "```{python #mycode .numberLines startFrom=\"100\"}\nprint(\"hello\")\n```\n";
     let node = parse_blocks(input);
 
-    assert_block_kinds(input, &[SyntaxKind::CodeBlock]);
+    // An executable cell keeps the whole info string
+    assert_block_kinds(input, &[SyntaxKind::CodeCell]);
 
     let content = get_code_content(&node).unwrap();
     assert_eq!(content, "print(\"hello\")");
@@ -96,14 +98,45 @@ fn parses_multiline_code_block() {
 }
 
 #[test]
-fn requires_blank_line_before_code_block() {
+fn backtick_fence_interrupts_paragraph() {
     let input = "text\n```\ncode\n```\n";
+
+    assert_block_kinds(input, &[SyntaxKind::PARAGRAPH, SyntaxKind::CodeBlock]);
+}
+
+#[test]
+fn tilde_fence_does_not_interrupt_paragraph() {
+    let input = "text\n~~~\ncode\n~~~\n";
     let node = parse_blocks(input);
 
     // Should parse as paragraph, not code block
     assert!(find_first(&node, SyntaxKind::CodeBlock).is_none());
 }
 
+#[test]
+fn unclosed_fence_does_not_interrupt_paragraph() {
+    let input = "text\n```\ncode\n";
+
+    assert_block_kinds(input, &[SyntaxKind::PARAGRAPH]);
+}
+
+#[test]
+fn backtick_info_cannot_contain_backticks() {
+    // The first line is inline code, and the bare fence is never closed
+    let input = "```foo`bar\ncode\n```\n";
+
+    assert_block_kinds(input, &[SyntaxKind::PARAGRAPH]);
+}
+
+#[test]
+fn tilde_info_can_contain_backticks() {
+    let input = "~~~ foo`bar\ncode\n~~~\n";
+    let node = parse_blocks(input);
+
+    assert_block_kinds(input, &[SyntaxKind::CodeBlock]);
+    assert_eq!(get_code_info(&node).unwrap(), "foo`bar");
+}
+
 #[test]
 fn parses_code_block_at_start_of_document() {
     let input = "```\ncode\n```\n";
@@ -191,3 +224,90 @@ fn code_block_with_leading_spaces() {
     let content = get_code_content(&node).unwrap();
     assert_eq!(content, "  print(\"hello\")");
 }
+
+#[test]
+fn parses_indented_code_block() {
+    let input = "Text.\n\n    fn main() {\n\n        println!();\n    }\n\nMore text.\n";
+    assert_block_kinds(
+        input,
+        &[
+            SyntaxKind::PARAGRAPH,
+            SyntaxKind::BlankLine,
+            SyntaxKind::IndentedCodeBlock,
+            SyntaxKind::BlankLine,
+            SyntaxKind::PARAGRAPH,
+        ],
+    );
+    let node = parse_blocks(input);
+    let code = find_first(&node, SyntaxKind::IndentedCodeBlock).unwrap();
+    assert_eq!(
+        code.text().to_string(),
+        "    fn main() {\n\n        println!();\n    }\n"
+    );
+    assert_eq!(node.text().to_string(), input);
+}
+
+#[test]
+fn tab_indented_code_block() {
+    assert_block_kinds("\tcode\n", &[SyntaxKind::IndentedCodeBlock]);
+}
+
+#[test]
+fn indented_code_cannot_interrupt_paragraph() {
+    assert_block_kinds("Text\n    more text\n", &[SyntaxKind::PARAGRAPH]);
+}
+
+#[test]
+fn indented_code_in_list_item() {
+    let input = "- item\n\n      code\n";
+    let node = parse_blocks(input);
+    let item = find_first(&node, SyntaxKind::ListItem).unwrap();
+    let code = find_first(&item, SyntaxKind::IndentedCodeBlock).unwrap();
+    assert_eq!(code.text().to_string(), "    code\n");
+}
+
+#[test]
+fn list_continuation_is_not_indented_code() {
+    let node = parse_blocks("1. item\n\n    continued\n");
+    assert!(find_first(&node, SyntaxKind::IndentedCodeBlock).is_none());
+}
+
+#[test]
+fn fenced_code_block_is_lossless() {
+    let input = "  ```  {r}  \nx <- 1\n\n  y <- 2\n   ```  \n";
+    let node = parse_blocks(input);
+
+    assert_eq!(node.text().to_string(), input);
+    assert_eq!(get_code_info(&node).unwrap(), "{r}");
+    assert_eq!(get_code_content(&node).unwrap(), "x <- 1\n\n  y <- 2");
+}
+
+#[test]
+fn fence_newlines_belong_to_fence_nodes() {
+    let input = "```python\nprint(1)\n```\n";
+    let node = parse_blocks(input);
+
+    let open = find_first(&node, SyntaxKind::CodeFenceOpen).unwrap();
+    let kinds: Vec<_> = open.children_with_tokens().map(|e| e.kind()).collect();
+    assert_eq!(
+        kinds,
+        &[
+            SyntaxKind::CodeFenceMarker,
+            SyntaxKind::CodeInfo,
+            SyntaxKind::NEWLINE
+        ]
+    );
+    assert_eq!(open.text().to_string(), "```python\n");
+
+    let close = find_first(&node, SyntaxKind::CodeFenceClose).unwrap();
+    assert_eq!(close.text().to_string(), "```\n");
+}
+
+#[test]
+fn unclosed_code_block_is_lossless() {
+    let input = "text\n\n```\ncode\n\nmore\n";
+    let node = parse_blocks(input);
+
+    assert_eq!(node.text().to_string(), input);
+    assert_eq!(get_code_content(&node).unwrap(), "code\n\nmore");
+}
@@ -0,0 +1,140 @@
+use crate::block_parser::tests::helpers::{assert_block_kinds, find_first, parse_blocks};
+use crate::syntax::{SyntaxKind, SyntaxNode};
+
+/// The key and value tokens of each CellOption, with multiple values joined
+/// by newlines.
+fn cell_options(node: &SyntaxNode) -> Vec<(String, String)> {
+    node.descendants()
+        .filter(|n| n.kind() == SyntaxKind::CellOption)
+        .map(|option| {
+            let texts = |kind| {
+                option
+                    .children_with_tokens()
+                    .filter(|el| el.kind() == kind)
+                    .map(|el| el.to_string())
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            };
+            (
+                texts(SyntaxKind::CellOptionKey),
+                texts(SyntaxKind::CellOptionValue),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn executable_cells_are_distinguished_from_code_blocks() {
+    assert_block_kinds("```{r}\nx <- 1\n```\n", &[SyntaxKind::CodeCell]);
+    assert_block_kinds(
+        "```{python echo=false}\nx = 1\n```\n",
+        &[SyntaxKind::CodeCell],
+    );
+    assert_block_kinds("```{r, echo=FALSE}\nx <- 1\n```\n", &[SyntaxKind::CodeCell]);
+    assert_block_kinds("```r\nx <- 1\n```\n", &[SyntaxKind::CodeBlock]);
+    assert_block_kinds("```{.r}\nx <- 1\n```\n", &[SyntaxKind::CodeBlock]);
+    assert_block_kinds("```{{r}}\nx <- 1\n```\n", &[SyntaxKind::CodeBlock]);
+}
+
+#[test]
+fn parses_cell_options() {
+    let input = "```{r}\n#| label: fig-plot\n#| echo: false\nplot(1:10)\n```\n";
+    let node = parse_blocks(input);
+
+    assert_eq!(
+        cell_options(&node),
+        &[
+            ("label".to_string(), "fig-plot".to_string()),
+            ("echo".to_string(), "false".to_string()),
+        ]
+    );
+    let content = find_first(&node, SyntaxKind::CodeContent).unwrap();
+    assert_eq!(content.text().to_string(), "plot(1:10)");
+    assert_eq!(node.text().to_string(), input);
+}
+
+#[test]
+fn option_comment_depends_on_language() {
+    let node = parse_blocks("```{ojs}\n//| echo: false\nviewof x = 1\n```\n");
+    assert_eq!(
+        cell_options(&node),
+        &[("echo".to_string(), "false".to_string())]
+    );
+
+    let node = parse_blocks("```{sql}\n--| label: query\nSELECT 1\n```\n");
+    assert_eq!(
+        cell_options(&node),
+        &[("label".to_string(), "query".to_string())]
+    );
+
+    let node = parse_blocks("```{ojs}\n#| echo: false\n```\n");
+    assert!(find_first(&node, SyntaxKind::CellOptions).is_none());
+}
+
+#[test]
+fn list_values_continue_an_option() {
+    let input =
+        "```{python}\n#| fig-cap:\n#|   - \"First\"\n#|   - \"Second\"\n#| echo: false\n```\n";
+    let node = parse_blocks(input);
+
+    assert_eq!(
+        cell_options(&node),
+        &[
+            (
+                "fig-cap".to_string(),
+                "- \"First\"\n- \"Second\"".to_string()
+            ),
+            ("echo".to_string(), "false".to_string()),
+        ]
+    );
+    assert!(find_first(&node, SyntaxKind::CodeContent).is_none());
+    assert_eq!(node.text().to_string(), input);
+}
+
+#[test]
+fn nested_mappings_continue_an_option() {
+    let input = "```{r}\n#| crossref:\n#|   fig-prefix: Figure\n#|   eq-prefix: Eq.\n#| echo: false\n```\n";
+    let node = parse_blocks(input);
+
+    assert_eq!(
+        cell_options(&node),
+        &[
+            (
+                "crossref".to_string(),
+                "fig-prefix: Figure\neq-prefix: Eq.".to_string()
+            ),
+            ("echo".to_string(), "false".to_string()),
+        ]
+    );
+    assert_eq!(node.text().to_string(), input);
+}
+
+#[test]
+fn base_indentation_follows_first_option() {
+    let input = "```{r}\n#|label: a\n#|echo: false\n#|  inner: 1\n```\n";
+    let node = parse_blocks(input);
+
+    assert_eq!(
+        cell_options(&node),
+        &[
+            ("label".to_string(), "a".to_string()),
+            ("echo".to_string(), "false\ninner: 1".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn only_leading_option_lines_are_options() {
+    let input = "```{r}\nx <- 1\n#| echo: false\n```\n";
+    let node = parse_blocks(input);
+
+    assert!(find_first(&node, SyntaxKind::CellOptions).is_none());
+    let content = find_first(&node, SyntaxKind::CodeContent).unwrap();
+    assert_eq!(content.text().to_string(), "x <- 1\n#| echo: false");
+}
+
+#[test]
+fn plain_code_block_has_no_options() {
+    let node = parse_blocks("```r\n#| echo: false\n```\n");
+    assert!(find_first(&node, SyntaxKind::CellOptions).is_none());
+}
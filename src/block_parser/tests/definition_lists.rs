@@ -0,0 +1,194 @@
+use crate::block_parser::tests::helpers::{assert_block_kinds, find_first, parse_blocks};
+use crate::syntax::{SyntaxKind, SyntaxNode};
+
+fn child_kinds(node: &SyntaxNode) -> Vec<SyntaxKind> {
+    node.children().map(|n| n.kind()).collect()
+}
+
+#[test]
+fn parses_compact_definition_list() {
+    let input = "Term\n:   Definition\n";
+    assert_block_kinds(input, &[SyntaxKind::DefinitionList]);
+
+    let node = parse_blocks(input);
+    let item = find_first(&node, SyntaxKind::DefinitionItem).unwrap();
+    assert_eq!(
+        child_kinds(&item),
+        &[SyntaxKind::Term, SyntaxKind::Definition]
+    );
+    let term = find_first(&item, SyntaxKind::Term).unwrap();
+    assert_eq!(term.text().to_string(), "Term");
+
+    let definition = find_first(&item, SyntaxKind::Definition).unwrap();
+    let marker = definition
+        .children_with_tokens()
+        .filter_map(|el| el.into_token())
+        .find(|t| t.kind() == SyntaxKind::DefinitionMarker)
+        .unwrap();
+    assert_eq!(marker.text(), ":");
+    assert_eq!(child_kinds(&definition), &[SyntaxKind::PARAGRAPH]);
+}
+
+#[test]
+fn parses_loose_definition_with_tilde_marker() {
+    let input = "Term\n\n~ Definition\n";
+    assert_block_kinds(input, &[SyntaxKind::DefinitionList]);
+
+    let node = parse_blocks(input);
+    let item = find_first(&node, SyntaxKind::DefinitionItem).unwrap();
+    assert_eq!(
+        child_kinds(&item),
+        &[
+            SyntaxKind::Term,
+            SyntaxKind::BlankLine,
+            SyntaxKind::Definition
+        ]
+    );
+}
+
+#[test]
+fn term_with_several_definitions() {
+    let input = "Term\n:   One\n:   Two\n\n:   Three\n";
+    let node = parse_blocks(input);
+    let item = find_first(&node, SyntaxKind::DefinitionItem).unwrap();
+    assert_eq!(
+        child_kinds(&item),
+        &[
+            SyntaxKind::Term,
+            SyntaxKind::Definition,
+            SyntaxKind::Definition,
+            SyntaxKind::BlankLine,
+            SyntaxKind::Definition
+        ]
+    );
+}
+
+#[test]
+fn definition_with_several_blocks() {
+    let input = "Term\n\n:   First paragraph\n    continues.\n\n    Second paragraph.\n\n        code\n\nAfter.\n";
+    assert_block_kinds(
+        input,
+        &[
+            SyntaxKind::DefinitionList,
+            SyntaxKind::BlankLine,
+            SyntaxKind::PARAGRAPH,
+        ],
+    );
+
+    let node = parse_blocks(input);
+    let definition = find_first(&node, SyntaxKind::Definition).unwrap();
+    assert_eq!(
+        child_kinds(&definition),
+        &[
+            SyntaxKind::PARAGRAPH,
+            SyntaxKind::BlankLine,
+            SyntaxKind::PARAGRAPH,
+            SyntaxKind::BlankLine,
+            SyntaxKind::IndentedCodeBlock
+        ]
+    );
+}
+
+#[test]
+fn loose_definition_continues_lazily() {
+    let input = "Term\n\n:   Definition\nlazily continued.\n";
+    let node = parse_blocks(input);
+    let definition = find_first(&node, SyntaxKind::Definition).unwrap();
+    assert_eq!(child_kinds(&definition), &[SyntaxKind::PARAGRAPH]);
+    assert!(
+        definition
+            .text()
+            .to_string()
+            .ends_with("lazily continued.\n")
+    );
+}
+
+#[test]
+fn lazy_line_after_overindented_continuation() {
+    let input = "Term\n\n:   Definition\n      more text\nlazy text.\n";
+    let node = parse_blocks(input);
+    let definition = find_first(&node, SyntaxKind::Definition).unwrap();
+    assert_eq!(child_kinds(&definition), &[SyntaxKind::PARAGRAPH]);
+    let paragraph = find_first(&definition, SyntaxKind::PARAGRAPH).unwrap();
+    assert_eq!(
+        paragraph.text().to_string(),
+        "Definition\n  more text\nlazy text.\n"
+    );
+}
+
+#[test]
+fn compact_definition_does_not_continue_lazily() {
+    let input = "Term\n:   Definition\nnot continued.\n";
+    assert_block_kinds(input, &[SyntaxKind::DefinitionList, SyntaxKind::PARAGRAPH]);
+}
+
+#[test]
+fn compact_definitions_keep_their_terms() {
+    let input = "Term 1\n:   Def 1\nTerm 2\n:   Def 2\n";
+    let node = parse_blocks(input);
+    let terms: Vec<_> = node
+        .descendants()
+        .filter(|n| n.kind() == SyntaxKind::Term)
+        .map(|n| n.text().to_string())
+        .collect();
+    assert_eq!(terms, &["Term 1", "Term 2"]);
+}
+
+#[test]
+fn lazy_line_does_not_swallow_next_item() {
+    let input = "Term 1\n\n:   Def 1\nTerm 2\n:   Def 2\n";
+    let node = parse_blocks(input);
+    let items = node
+        .descendants()
+        .filter(|n| n.kind() == SyntaxKind::DefinitionItem)
+        .count();
+    assert_eq!(items, 2);
+}
+
+#[test]
+fn items_are_separated_by_blank_lines() {
+    let input = "Apple\n:   A fruit.\n\nCarrot\n:   A vegetable.\n";
+    assert_block_kinds(input, &[SyntaxKind::DefinitionList]);
+
+    let node = parse_blocks(input);
+    let list = find_first(&node, SyntaxKind::DefinitionList).unwrap();
+    assert_eq!(
+        child_kinds(&list),
+        &[
+            SyntaxKind::DefinitionItem,
+            SyntaxKind::BlankLine,
+            SyntaxKind::DefinitionItem
+        ]
+    );
+}
+
+#[test]
+fn marker_needs_following_space() {
+    let input = "Term\n:Definition\n";
+    assert_block_kinds(input, &[SyntaxKind::PARAGRAPH]);
+}
+
+#[test]
+fn marker_indented_three_spaces_is_not_a_definition() {
+    let input = "Term\n   : Definition\n";
+    assert_block_kinds(input, &[SyntaxKind::PARAGRAPH]);
+}
+
+#[test]
+fn paragraph_line_is_not_a_term() {
+    let input = "Some text\nTerm\n:   Definition\n";
+    assert_block_kinds(input, &[SyntaxKind::PARAGRAPH]);
+}
+
+#[test]
+fn table_caption_is_not_a_definition() {
+    let input = "| a | b |\n|---|---|\n| 1 | 2 |\n\n: Caption\n";
+    assert_block_kinds(input, &[SyntaxKind::PipeTable]);
+}
+
+#[test]
+fn definition_list_is_lossless() {
+    let input = "  Term  \n\n ~  Definition\nlazy\n\nNext\n: Other\n";
+    let node = parse_blocks(input);
+    assert_eq!(node.text().to_string(), input);
+}
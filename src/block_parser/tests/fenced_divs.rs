@@ -0,0 +1,131 @@
+use crate::block_parser::tests::helpers::{assert_block_kinds, find_first, parse_blocks};
+use crate::syntax::{SyntaxKind, SyntaxNode};
+
+fn get_div_info(node: &SyntaxNode) -> Option<String> {
+    find_first(node, SyntaxKind::DivInfo).map(|n| n.text().to_string())
+}
+
+fn content_kinds(div: &SyntaxNode) -> Vec<SyntaxKind> {
+    div.children()
+        .find(|n| n.kind() == SyntaxKind::DivContent)
+        .unwrap()
+        .children()
+        .map(|n| n.kind())
+        .collect()
+}
+
+#[test]
+fn parses_simple_fenced_div() {
+    let input = "::: {.callout-note}\nSome content.\n:::\n";
+    assert_block_kinds(input, &[SyntaxKind::FencedDiv]);
+
+    let node = parse_blocks(input);
+    assert_eq!(get_div_info(&node).unwrap(), "{.callout-note}");
+
+    let div = find_first(&node, SyntaxKind::FencedDiv).unwrap();
+    assert_eq!(content_kinds(&div), &[SyntaxKind::PARAGRAPH]);
+    assert!(find_first(&div, SyntaxKind::DivFenceClose).is_some());
+}
+
+#[test]
+fn parses_bare_class_info_and_trailing_colons() {
+    let node = parse_blocks("::::: warning :::::\ntext\n:::::\n");
+    assert_eq!(get_div_info(&node).unwrap(), "warning");
+}
+
+#[test]
+fn bare_fence_does_not_open_div() {
+    let node = parse_blocks(":::\ntext\n:::\n");
+    assert!(find_first(&node, SyntaxKind::FencedDiv).is_none());
+}
+
+#[test]
+fn fence_lines_are_lossless() {
+    let input = "::: {.callout-tip title=\"Tip\"}\nContent\n:::\n";
+    let node = parse_blocks(input);
+    let div = find_first(&node, SyntaxKind::FencedDiv).unwrap();
+    assert_eq!(div.text().to_string(), input);
+}
+
+#[test]
+fn heading_directly_after_opening_fence() {
+    let node = parse_blocks("::: {.callout-note}\n## Title\n\nBody\n:::\n");
+    let div = find_first(&node, SyntaxKind::FencedDiv).unwrap();
+    assert_eq!(
+        content_kinds(&div),
+        &[
+            SyntaxKind::Heading,
+            SyntaxKind::BlankLine,
+            SyntaxKind::PARAGRAPH
+        ]
+    );
+}
+
+#[test]
+fn closing_fence_ends_paragraph() {
+    let input = "::: note\nline one\nline two\n:::\nAfter\n";
+    assert_block_kinds(input, &[SyntaxKind::FencedDiv, SyntaxKind::PARAGRAPH]);
+}
+
+#[test]
+fn nested_divs_with_different_fence_lengths() {
+    let input = ":::: {.columns}\n::: {.column}\nLeft\n:::\n::: {.column}\nRight\n:::\n::::\n";
+    assert_block_kinds(input, &[SyntaxKind::FencedDiv]);
+
+    let node = parse_blocks(input);
+    let outer = find_first(&node, SyntaxKind::FencedDiv).unwrap();
+    assert_eq!(
+        content_kinds(&outer),
+        &[SyntaxKind::FencedDiv, SyntaxKind::FencedDiv]
+    );
+}
+
+#[test]
+fn nested_divs_with_same_fence_length() {
+    let input = "::: outer\n::: inner\ntext\n:::\n:::\n";
+    assert_block_kinds(input, &[SyntaxKind::FencedDiv]);
+
+    let node = parse_blocks(input);
+    let outer = find_first(&node, SyntaxKind::FencedDiv).unwrap();
+    assert_eq!(content_kinds(&outer), &[SyntaxKind::FencedDiv]);
+}
+
+#[test]
+fn code_block_inside_div_keeps_colons() {
+    let input = "::: {.panel}\n\n```\n:::\n```\n\n:::\n";
+    assert_block_kinds(input, &[SyntaxKind::FencedDiv]);
+
+    let node = parse_blocks(input);
+    let code = find_first(&node, SyntaxKind::CodeContent).unwrap();
+    assert_eq!(code.text().to_string(), ":::");
+}
+
+#[test]
+fn unclosed_div_extends_to_end_of_document() {
+    let input = "::: {.callout-note}\nNever closed\n\nStill inside\n";
+    assert_block_kinds(input, &[SyntaxKind::FencedDiv]);
+
+    let node = parse_blocks(input);
+    assert!(find_first(&node, SyntaxKind::DivFenceClose).is_none());
+}
+
+#[test]
+fn unclosed_inner_div_takes_outer_closing_fence() {
+    let input = "::: outer\n::: inner\ntext\n:::\n";
+    let node = parse_blocks(input);
+    let outer = find_first(&node, SyntaxKind::FencedDiv).unwrap();
+    assert!(
+        outer
+            .children()
+            .all(|n| n.kind() != SyntaxKind::DivFenceClose)
+    );
+    let inner = find_first(&outer.children().nth(1).unwrap(), SyntaxKind::FencedDiv).unwrap();
+    assert!(find_first(&inner, SyntaxKind::DivFenceClose).is_some());
+}
+
+#[test]
+fn blockquote_inside_div() {
+    let node = parse_blocks("::: note\n> quoted\n:::\n");
+    let div = find_first(&node, SyntaxKind::FencedDiv).unwrap();
+    assert_eq!(content_kinds(&div), &[SyntaxKind::BlockQuote]);
+}
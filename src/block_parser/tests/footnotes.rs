@@ -0,0 +1,102 @@
+use crate::block_parser::tests::helpers::{assert_block_kinds, find_first, parse_blocks};
+use crate::syntax::{SyntaxKind, SyntaxNode};
+
+fn note_content_kinds(note: &SyntaxNode) -> Vec<SyntaxKind> {
+    note.children()
+        .find(|n| n.kind() == SyntaxKind::FootnoteContent)
+        .unwrap()
+        .children()
+        .map(|n| n.kind())
+        .collect()
+}
+
+#[test]
+fn parses_footnote_definition() {
+    let input = "[^1]: A note.\n";
+    assert_block_kinds(input, &[SyntaxKind::FootnoteDefinition]);
+
+    let node = parse_blocks(input);
+    let note = find_first(&node, SyntaxKind::FootnoteDefinition).unwrap();
+    let marker = note
+        .children_with_tokens()
+        .filter_map(|el| el.into_token())
+        .find(|t| t.kind() == SyntaxKind::FootnoteDefinitionMarker)
+        .unwrap();
+    assert_eq!(marker.text(), "[^1]:");
+    assert_eq!(note_content_kinds(&note), &[SyntaxKind::PARAGRAPH]);
+}
+
+#[test]
+fn footnote_with_indented_paragraphs() {
+    let input =
+        "[^note]: First paragraph\n    continues here.\n\n    Second paragraph.\n\nAfter.\n";
+    assert_block_kinds(
+        input,
+        &[
+            SyntaxKind::FootnoteDefinition,
+            SyntaxKind::BlankLine,
+            SyntaxKind::PARAGRAPH,
+        ],
+    );
+
+    let node = parse_blocks(input);
+    let note = find_first(&node, SyntaxKind::FootnoteDefinition).unwrap();
+    assert_eq!(
+        note_content_kinds(&note),
+        &[
+            SyntaxKind::PARAGRAPH,
+            SyntaxKind::BlankLine,
+            SyntaxKind::PARAGRAPH
+        ]
+    );
+}
+
+#[test]
+fn footnote_lazy_continuation() {
+    let input = "[^1]: First line\nlazy continuation.\n";
+    assert_block_kinds(input, &[SyntaxKind::FootnoteDefinition]);
+}
+
+#[test]
+fn lazy_line_after_overindented_continuation() {
+    let input = "[^1]: First line\n      more text\nlazy text.\n";
+    assert_block_kinds(input, &[SyntaxKind::FootnoteDefinition]);
+
+    let node = parse_blocks(input);
+    let paragraph = find_first(&node, SyntaxKind::PARAGRAPH).unwrap();
+    assert_eq!(
+        paragraph.text().to_string(),
+        "First line\n  more text\nlazy text.\n"
+    );
+}
+
+#[test]
+fn consecutive_footnote_definitions() {
+    let input = "[^1]: One.\n[^2]: Two.\n";
+    assert_block_kinds(
+        input,
+        &[
+            SyntaxKind::FootnoteDefinition,
+            SyntaxKind::FootnoteDefinition,
+        ],
+    );
+}
+
+#[test]
+fn unindented_paragraph_after_blank_ends_footnote() {
+    let input = "[^1]: One.\n\nNot part of the note.\n";
+    assert_block_kinds(
+        input,
+        &[
+            SyntaxKind::FootnoteDefinition,
+            SyntaxKind::BlankLine,
+            SyntaxKind::PARAGRAPH,
+        ],
+    );
+}
+
+#[test]
+fn footnote_reference_alone_is_not_a_definition() {
+    assert_block_kinds("[^1] is not a definition.\n", &[SyntaxKind::PARAGRAPH]);
+    assert_block_kinds("[^a b]: has a space.\n", &[SyntaxKind::PARAGRAPH]);
+}
@@ -0,0 +1,98 @@
+use crate::block_parser::tests::helpers::{assert_block_kinds, find_first, parse_blocks};
+use crate::syntax::SyntaxKind;
+
+fn get_frontmatter_content(input: &str) -> Option<String> {
+    let node = parse_blocks(input);
+    find_first(&node, SyntaxKind::FrontmatterContent).map(|n| n.text().to_string())
+}
+
+#[test]
+fn parses_frontmatter_at_start() {
+    let input = "---\ntitle: hi\n---\n\nHello world\n";
+    assert_block_kinds(
+        input,
+        &[
+            SyntaxKind::FRONTMATTER,
+            SyntaxKind::BlankLine,
+            SyntaxKind::PARAGRAPH,
+        ],
+    );
+    assert_eq!(get_frontmatter_content(input).unwrap(), "title: hi\n");
+}
+
+#[test]
+fn frontmatter_is_lossless() {
+    let input = "---\ntitle: \"A  title\"\nauthor:\n  - name: Me\n---\n";
+    let node = parse_blocks(input);
+    let frontmatter = find_first(&node, SyntaxKind::FRONTMATTER).unwrap();
+    assert_eq!(frontmatter.text().to_string(), input);
+}
+
+#[test]
+fn frontmatter_can_close_with_dots() {
+    let input = "---\ntitle: hi\n...\n";
+    assert_block_kinds(input, &[SyntaxKind::FRONTMATTER]);
+}
+
+#[test]
+fn unclosed_frontmatter_is_not_frontmatter() {
+    let node = parse_blocks("---\ntitle: hi\n");
+    assert!(find_first(&node, SyntaxKind::FRONTMATTER).is_none());
+}
+
+#[test]
+fn opening_delimiter_must_not_be_followed_by_blank_line() {
+    let node = parse_blocks("---\n\ntitle: hi\n---\n");
+    assert!(find_first(&node, SyntaxKind::FRONTMATTER).is_none());
+}
+
+#[test]
+fn metadata_block_later_in_document() {
+    let input = "Some text\n\n---\nfoo: bar\n---\n";
+    assert_block_kinds(
+        input,
+        &[
+            SyntaxKind::PARAGRAPH,
+            SyntaxKind::BlankLine,
+            SyntaxKind::FRONTMATTER,
+        ],
+    );
+}
+
+#[test]
+fn metadata_block_requires_blank_line_before() {
+    let node = parse_blocks("Some text\n---\nfoo: bar\n---\n");
+    assert!(find_first(&node, SyntaxKind::FRONTMATTER).is_none());
+}
+
+#[test]
+fn later_metadata_block_must_start_with_a_key() {
+    let input = "Intro\n\n---\nSome text here.\n\nAnother paragraph.\n\n---\n\nEnd\n";
+    let node = parse_blocks(input);
+    assert!(find_first(&node, SyntaxKind::FRONTMATTER).is_none());
+}
+
+#[test]
+fn later_metadata_block_may_start_with_a_comment() {
+    let input = "Intro\n\n---\n# A comment\nfoo: bar\n---\n";
+    assert_block_kinds(
+        input,
+        &[
+            SyntaxKind::PARAGRAPH,
+            SyntaxKind::BlankLine,
+            SyntaxKind::FRONTMATTER,
+        ],
+    );
+}
+
+#[test]
+fn later_metadata_block_stops_at_blank_line() {
+    let node = parse_blocks("Intro\n\n---\nfoo: bar\n\nbaz: qux\n---\n");
+    assert!(find_first(&node, SyntaxKind::FRONTMATTER).is_none());
+}
+
+#[test]
+fn frontmatter_at_start_may_contain_blank_lines() {
+    let input = "---\ntitle: hi\n\nauthor: me\n---\n";
+    assert_block_kinds(input, &[SyntaxKind::FRONTMATTER]);
+}
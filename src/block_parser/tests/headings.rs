@@ -1,4 +1,4 @@
-use crate::block_parser::tests::helpers::{find_first, parse_blocks};
+use crate::block_parser::tests::helpers::{assert_block_kinds, find_first, parse_blocks};
 use crate::syntax::{SyntaxKind, SyntaxNode};
 
 fn get_heading_content(node: &SyntaxNode) -> Option<String> {
@@ -68,3 +68,132 @@ fn parses_multiple_headings() {
     assert_eq!(headings.next().unwrap().text(), "First");
     assert_eq!(headings.next().unwrap().text(), "Second");
 }
+
+#[test]
+fn parses_setext_headings() {
+    let input = "Title\n=====\n\nSubtitle\n--------\n";
+    let node = parse_blocks(input);
+    assert_eq!(node.text().to_string(), input);
+
+    let mut headings = node
+        .descendants()
+        .filter(|n| n.kind() == SyntaxKind::HeadingContent);
+    assert_eq!(headings.next().unwrap().text(), "Title");
+    assert_eq!(headings.next().unwrap().text(), "Subtitle");
+
+    let underlines: Vec<_> = node
+        .descendants()
+        .filter(|n| n.kind() == SyntaxKind::SetextHeadingUnderline)
+        .map(|n| n.text().to_string())
+        .collect();
+    assert_eq!(underlines, &["=====", "--------"]);
+}
+
+#[test]
+fn setext_heading_is_lossless_with_whitespace() {
+    let input = "  Title  \n  ===  \n";
+    let node = parse_blocks(input);
+    assert_eq!(node.text().to_string(), input);
+    assert_eq!(get_heading_content(&node).unwrap(), "Title");
+}
+
+#[test]
+fn setext_heading_ends_before_following_text() {
+    assert_block_kinds(
+        "Heading\n-------\nText\n",
+        &[SyntaxKind::Heading, SyntaxKind::PARAGRAPH],
+    );
+}
+
+#[test]
+fn setext_heading_requires_blank_line_before() {
+    let node = parse_blocks("text\nTitle\n=====\n");
+    assert!(find_first(&node, SyntaxKind::Heading).is_none());
+}
+
+#[test]
+fn setext_heading_text_is_a_single_line() {
+    let node = parse_blocks("foo\nbar\n---\n");
+    assert!(find_first(&node, SyntaxKind::Heading).is_none());
+}
+
+#[test]
+fn list_item_is_not_setext_heading() {
+    let node = parse_blocks("- item\n\n---\n");
+    assert!(find_first(&node, SyntaxKind::Heading).is_none());
+}
+
+fn attribute_parts(node: &SyntaxNode) -> Vec<(SyntaxKind, String)> {
+    let attribute = find_first(node, SyntaxKind::Attribute).unwrap();
+    attribute
+        .children_with_tokens()
+        .filter(|el| el.kind() != SyntaxKind::WHITESPACE && el.kind() != SyntaxKind::TEXT)
+        .map(|el| (el.kind(), el.to_string()))
+        .collect()
+}
+
+#[test]
+fn parses_heading_attributes() {
+    let node = parse_blocks("## Results {#sec-results .unnumbered key=\"a value\"}\n");
+    assert_eq!(get_heading_content(&node).unwrap(), "Results");
+
+    let attribute = find_first(&node, SyntaxKind::Attribute).unwrap();
+    assert_eq!(
+        attribute.text().to_string(),
+        "{#sec-results .unnumbered key=\"a value\"}"
+    );
+    assert_eq!(
+        attribute_parts(&node),
+        &[
+            (SyntaxKind::AttributeId, "#sec-results".to_string()),
+            (SyntaxKind::AttributeClass, ".unnumbered".to_string()),
+            (SyntaxKind::AttributeKeyValue, "key=\"a value\"".to_string()),
+        ]
+    );
+
+    let pair = find_first(&node, SyntaxKind::AttributeKeyValue).unwrap();
+    let tokens: Vec<_> = pair
+        .children_with_tokens()
+        .map(|el| (el.kind(), el.to_string()))
+        .collect();
+    assert_eq!(
+        tokens,
+        &[
+            (SyntaxKind::AttributeKey, "key".to_string()),
+            (SyntaxKind::TEXT, "=".to_string()),
+            (SyntaxKind::AttributeValue, "\"a value\"".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn unnumbered_shorthand_is_a_class() {
+    let node = parse_blocks("# Preface {-}\n");
+    assert_eq!(
+        attribute_parts(&node),
+        &[(SyntaxKind::AttributeClass, "-".to_string())]
+    );
+}
+
+#[test]
+fn setext_heading_attributes() {
+    let input = "Introduction {#sec-intro}\n============\n";
+    let node = parse_blocks(input);
+    assert_eq!(node.text().to_string(), input);
+    assert_eq!(get_heading_content(&node).unwrap(), "Introduction");
+    assert_eq!(
+        attribute_parts(&node),
+        &[(SyntaxKind::AttributeId, "#sec-intro".to_string())]
+    );
+}
+
+#[test]
+fn invalid_attribute_block_stays_in_content() {
+    for input in ["# Sets {a, b}\n", "# Empty id {#}\n", "# Braces {#a}b\n"] {
+        let node = parse_blocks(input);
+        assert!(
+            find_first(&node, SyntaxKind::Attribute).is_none(),
+            "{input}"
+        );
+    }
+}
@@ -0,0 +1,92 @@
+use crate::block_parser::tests::helpers::{assert_block_kinds, find_first, parse_blocks};
+use crate::syntax::SyntaxKind;
+
+#[test]
+fn parses_div_block_with_blank_lines() {
+    let input = "<div class=\"note\">\n\nSome *text*.\n\n<div>nested</div>\n\n</div>\n\nAfter.\n";
+    assert_block_kinds(
+        input,
+        &[
+            SyntaxKind::HtmlBlock,
+            SyntaxKind::BlankLine,
+            SyntaxKind::PARAGRAPH,
+        ],
+    );
+    let node = parse_blocks(input);
+    assert_eq!(node.text().to_string(), input);
+}
+
+#[test]
+fn parses_details_block() {
+    let input = "<details>\n<summary>More</summary>\n\nHidden.\n</details>\n";
+    assert_block_kinds(input, &[SyntaxKind::HtmlBlock]);
+}
+
+#[test]
+fn parses_script_and_style_blocks() {
+    assert_block_kinds(
+        "<script>\nlet x = 1;\n\nlet y = 2;\n</script>\n",
+        &[SyntaxKind::HtmlBlock],
+    );
+    assert_block_kinds(
+        "<STYLE>\np { color: red; }\n\n</STYLE>\n",
+        &[SyntaxKind::HtmlBlock],
+    );
+}
+
+#[test]
+fn unclosed_tag_ends_at_blank_line() {
+    assert_block_kinds(
+        "<div>\nunclosed\n\nText.\n",
+        &[
+            SyntaxKind::HtmlBlock,
+            SyntaxKind::BlankLine,
+            SyntaxKind::PARAGRAPH,
+        ],
+    );
+    assert_block_kinds(
+        "<hr>\n\nText.\n",
+        &[
+            SyntaxKind::HtmlBlock,
+            SyntaxKind::BlankLine,
+            SyntaxKind::PARAGRAPH,
+        ],
+    );
+}
+
+#[test]
+fn parses_multiline_comment() {
+    let input = "<!--\nA comment\n\nspanning lines\n-->\n";
+    assert_block_kinds(input, &[SyntaxKind::Comment]);
+
+    let node = parse_blocks(input);
+    let comment = find_first(&node, SyntaxKind::Comment).unwrap();
+    assert_eq!(comment.text().to_string(), input);
+    let tokens: Vec<_> = comment.children_with_tokens().map(|el| el.kind()).collect();
+    assert_eq!(tokens.first(), Some(&SyntaxKind::CommentStart));
+    assert_eq!(tokens[tokens.len() - 2], SyntaxKind::CommentEnd);
+}
+
+#[test]
+fn single_line_comment_block() {
+    assert_block_kinds(
+        "<!-- note -->\nText.\n",
+        &[SyntaxKind::Comment, SyntaxKind::PARAGRAPH],
+    );
+}
+
+#[test]
+fn unclosed_comment_is_paragraph() {
+    assert_block_kinds("<!-- not closed\n", &[SyntaxKind::PARAGRAPH]);
+}
+
+#[test]
+fn inline_tags_do_not_start_blocks() {
+    assert_block_kinds("<span>text</span> more text\n", &[SyntaxKind::PARAGRAPH]);
+    assert_block_kinds("<divider>\n", &[SyntaxKind::PARAGRAPH]);
+}
+
+#[test]
+fn html_block_does_not_interrupt_paragraph() {
+    assert_block_kinds("Text\n<div>\n</div>\n", &[SyntaxKind::PARAGRAPH]);
+}
@@ -0,0 +1,82 @@
+use crate::block_parser::tests::helpers::{assert_block_kinds, find_first, parse_blocks};
+use crate::syntax::SyntaxKind;
+
+#[test]
+fn parses_latex_environment() {
+    let input = "\\begin{align}\n  a &= b \\\\\n  c &= d\n\\end{align}\n";
+    assert_block_kinds(input, &[SyntaxKind::LatexEnvironment]);
+
+    let node = parse_blocks(input);
+    let env = find_first(&node, SyntaxKind::LatexEnvironment).unwrap();
+    assert_eq!(env.text().to_string(), input);
+    assert_eq!(
+        find_first(&env, SyntaxKind::LatexEnvBegin).unwrap().text(),
+        "\\begin{align}\n"
+    );
+    assert_eq!(
+        find_first(&env, SyntaxKind::LatexEnvContent)
+            .unwrap()
+            .text(),
+        "  a &= b \\\\\n  c &= d\n"
+    );
+    assert_eq!(
+        find_first(&env, SyntaxKind::LatexEnvEnd).unwrap().text(),
+        "\\end{align}\n"
+    );
+}
+
+#[test]
+fn nested_environments_of_same_name() {
+    let input = "\\begin{itemize}\n\\item a\n\\begin{itemize}\n\\item b\n\\end{itemize}\n\n\\item c\n\\end{itemize}\n\nText.\n";
+    assert_block_kinds(
+        input,
+        &[
+            SyntaxKind::LatexEnvironment,
+            SyntaxKind::BlankLine,
+            SyntaxKind::PARAGRAPH,
+        ],
+    );
+    let node = parse_blocks(input);
+    let env = find_first(&node, SyntaxKind::LatexEnvironment).unwrap();
+    assert!(
+        env.text()
+            .to_string()
+            .ends_with("\\item c\n\\end{itemize}\n")
+    );
+}
+
+#[test]
+fn unclosed_environment_is_not_parsed() {
+    let node = parse_blocks("\\begin{align}\na = b\n");
+    assert!(find_first(&node, SyntaxKind::LatexEnvironment).is_none());
+}
+
+#[test]
+fn parses_standalone_commands() {
+    assert_block_kinds("\\newpage\n", &[SyntaxKind::LatexCommand]);
+    assert_block_kinds(
+        "\\includegraphics[width=0.5\\textwidth]{figure.png}\n",
+        &[SyntaxKind::LatexCommand],
+    );
+    assert_block_kinds(
+        "\\clearpage \\newpage\nText.\n",
+        &[SyntaxKind::LatexCommand, SyntaxKind::PARAGRAPH],
+    );
+}
+
+#[test]
+fn command_arguments_may_span_lines() {
+    let input = "\\pdfpcnote{\n  a note\n}\n";
+    assert_block_kinds(input, &[SyntaxKind::LatexCommand]);
+    let node = parse_blocks(input);
+    assert_eq!(node.text().to_string(), input);
+}
+
+#[test]
+fn command_followed_by_text_is_paragraph() {
+    assert_block_kinds(
+        "\\LaTeX{} is a typesetting system.\n",
+        &[SyntaxKind::PARAGRAPH],
+    );
+    assert_block_kinds("\\*not a command\n", &[SyntaxKind::PARAGRAPH]);
+}
@@ -0,0 +1,54 @@
+use crate::block_parser::tests::helpers::{assert_block_kinds, find_first, parse_blocks};
+use crate::syntax::SyntaxKind;
+
+#[test]
+fn parses_line_block() {
+    let input = "| Line one\n|    Line two\n";
+    assert_block_kinds(input, &[SyntaxKind::LineBlock]);
+
+    let node = parse_blocks(input);
+    let block = find_first(&node, SyntaxKind::LineBlock).unwrap();
+    let markers = block
+        .children_with_tokens()
+        .filter(|el| el.kind() == SyntaxKind::LineBlockMarker)
+        .count();
+    assert_eq!(markers, 2);
+    assert_eq!(block.text().to_string(), input);
+}
+
+#[test]
+fn continuation_line_starts_with_space() {
+    let input = "| The first line\n  continues here\n| Second line\n\nAfter.\n";
+    assert_block_kinds(
+        input,
+        &[
+            SyntaxKind::LineBlock,
+            SyntaxKind::BlankLine,
+            SyntaxKind::PARAGRAPH,
+        ],
+    );
+}
+
+#[test]
+fn empty_line_block_lines() {
+    let input = "| Stanza one\n|\n| Stanza two\n";
+    assert_block_kinds(input, &[SyntaxKind::LineBlock]);
+}
+
+#[test]
+fn unindented_line_ends_line_block() {
+    let input = "| Line\nText\n";
+    assert_block_kinds(input, &[SyntaxKind::LineBlock, SyntaxKind::PARAGRAPH]);
+}
+
+#[test]
+fn pipe_without_space_is_not_a_line_block() {
+    let input = "|text\n";
+    assert_block_kinds(input, &[SyntaxKind::PARAGRAPH]);
+}
+
+#[test]
+fn pipe_table_takes_precedence() {
+    let input = "| a | b |\n|---|---|\n| 1 | 2 |\n";
+    assert_block_kinds(input, &[SyntaxKind::PipeTable]);
+}
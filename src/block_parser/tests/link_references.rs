@@ -0,0 +1,103 @@
+use crate::block_parser::tests::helpers::{assert_block_kinds, find_first, parse_blocks};
+use crate::syntax::{SyntaxKind, SyntaxNode};
+
+fn token_texts(node: &SyntaxNode, kind: SyntaxKind) -> Vec<String> {
+    node.descendants_with_tokens()
+        .filter_map(|el| el.into_token())
+        .filter(|t| t.kind() == kind)
+        .map(|t| t.text().to_string())
+        .collect()
+}
+
+#[test]
+fn parses_link_reference_definition() {
+    let input = "[ref]: https://example.com \"Title\"\n";
+    assert_block_kinds(input, &[SyntaxKind::LinkReferenceDefinition]);
+
+    let node = parse_blocks(input);
+    assert_eq!(
+        token_texts(&node, SyntaxKind::LinkReferenceLabel),
+        &["[ref]"]
+    );
+    assert_eq!(
+        token_texts(&node, SyntaxKind::LinkUrl),
+        &["https://example.com"]
+    );
+    assert_eq!(token_texts(&node, SyntaxKind::LinkTitle), &["\"Title\""]);
+    assert_eq!(node.text().to_string(), input);
+}
+
+#[test]
+fn consecutive_definitions_are_separate_blocks() {
+    let input = "[a]: https://a.example.com\n[b]: https://b.example.com 'B'\n";
+    assert_block_kinds(
+        input,
+        &[
+            SyntaxKind::LinkReferenceDefinition,
+            SyntaxKind::LinkReferenceDefinition,
+        ],
+    );
+}
+
+#[test]
+fn angle_bracketed_destination() {
+    let input = "[ref]: <https://example.com/a b>\n";
+    let node = parse_blocks(input);
+    assert_eq!(
+        token_texts(&node, SyntaxKind::LinkUrl),
+        &["<https://example.com/a b>"]
+    );
+}
+
+#[test]
+fn destination_and_title_on_following_lines() {
+    let input = "[ref]:\n  https://example.com\n  (A title)\n\nText.\n";
+    assert_block_kinds(
+        input,
+        &[
+            SyntaxKind::LinkReferenceDefinition,
+            SyntaxKind::BlankLine,
+            SyntaxKind::PARAGRAPH,
+        ],
+    );
+
+    let node = parse_blocks(input);
+    assert_eq!(token_texts(&node, SyntaxKind::LinkTitle), &["(A title)"]);
+    assert_eq!(node.text().to_string(), input);
+}
+
+#[test]
+fn definition_with_attributes() {
+    let input = "[ref]: https://example.com \"Title\" {#id .external}\n";
+    let node = parse_blocks(input);
+    let definition = find_first(&node, SyntaxKind::LinkReferenceDefinition).unwrap();
+    let attribute = find_first(&definition, SyntaxKind::Attribute).unwrap();
+    assert_eq!(attribute.text().to_string(), "{#id .external}");
+}
+
+#[test]
+fn next_line_that_is_not_a_title_is_a_paragraph() {
+    let input = "[ref]: https://example.com\nSome text.\n";
+    assert_block_kinds(
+        input,
+        &[SyntaxKind::LinkReferenceDefinition, SyntaxKind::PARAGRAPH],
+    );
+}
+
+#[test]
+fn trailing_text_is_not_a_definition() {
+    let input = "[ref]: https://example.com \"Title\" and more\n";
+    assert_block_kinds(input, &[SyntaxKind::PARAGRAPH]);
+}
+
+#[test]
+fn definition_cannot_interrupt_paragraph() {
+    let input = "Some text\n[ref]: https://example.com\n";
+    assert_block_kinds(input, &[SyntaxKind::PARAGRAPH]);
+}
+
+#[test]
+fn footnote_definition_is_not_a_link_reference() {
+    let input = "[^1]: A note.\n";
+    assert_block_kinds(input, &[SyntaxKind::FootnoteDefinition]);
+}
@@ -0,0 +1,297 @@
+use crate::block_parser::is_loose_list;
+use crate::block_parser::tests::helpers::{assert_block_kinds, find_first, parse_blocks};
+use crate::syntax::{SyntaxKind, SyntaxNode};
+
+fn list_markers(node: &SyntaxNode) -> Vec<String> {
+    node.descendants_with_tokens()
+        .filter_map(|el| el.into_token())
+        .filter(|t| t.kind() == SyntaxKind::ListMarker)
+        .map(|t| t.text().to_string())
+        .collect()
+}
+
+fn items(list: &SyntaxNode) -> Vec<SyntaxNode> {
+    list.children()
+        .filter(|n| n.kind() == SyntaxKind::ListItem)
+        .collect()
+}
+
+fn item_content_kinds(item: &SyntaxNode) -> Vec<SyntaxKind> {
+    item.children()
+        .find(|n| n.kind() == SyntaxKind::ItemContent)
+        .unwrap()
+        .children()
+        .map(|n| n.kind())
+        .collect()
+}
+
+#[test]
+fn parses_bullet_list() {
+    let input = "- one\n- two\n- three\n";
+    assert_block_kinds(input, &[SyntaxKind::List]);
+
+    let node = parse_blocks(input);
+    let list = find_first(&node, SyntaxKind::List).unwrap();
+    assert_eq!(items(&list).len(), 3);
+    assert_eq!(list_markers(&node), &["-", "-", "-"]);
+}
+
+#[test]
+fn parses_all_bullet_characters() {
+    for marker in ["-", "+", "*"] {
+        let input = format!("{marker} item\n");
+        assert_block_kinds(&input, &[SyntaxKind::List]);
+    }
+}
+
+#[test]
+fn different_bullets_start_new_lists() {
+    let node = parse_blocks("- one\n* two\n");
+    let document = node.first_child().unwrap();
+    let lists = document
+        .children()
+        .filter(|n| n.kind() == SyntaxKind::List)
+        .count();
+    assert_eq!(lists, 2);
+}
+
+#[test]
+fn parses_ordered_markers() {
+    for marker in [
+        "1.",
+        "1)",
+        "(1)",
+        "a.",
+        "b)",
+        "(c)",
+        "i.",
+        "iv)",
+        "xii.",
+        "(mcmxcix)",
+        "A.",
+        "IV.",
+        "#.",
+    ] {
+        let spaces = if marker == "A." { "  " } else { " " };
+        let input = format!("{marker}{spaces}item\n");
+        let node = parse_blocks(&input);
+        assert_eq!(list_markers(&node), &[marker], "marker: {marker}");
+    }
+}
+
+#[test]
+fn uppercase_letter_with_period_needs_two_spaces() {
+    let node = parse_blocks("B. Russell was a philosopher.\n");
+    assert!(find_first(&node, SyntaxKind::List).is_none());
+}
+
+#[test]
+fn words_are_not_roman_numerals() {
+    for input in [
+        "did. it\n",
+        "dim) light\n",
+        "Mild. weather\n",
+        "Ix. mixed case\n",
+    ] {
+        let node = parse_blocks(input);
+        assert!(find_first(&node, SyntaxKind::List).is_none(), "{input}");
+    }
+}
+
+#[test]
+fn uppercase_roman_letter_with_period_needs_two_spaces() {
+    let node = parse_blocks("I. Newton was a physicist.\n");
+    assert!(find_first(&node, SyntaxKind::List).is_none());
+
+    let node = parse_blocks("I.  First\n");
+    assert!(find_first(&node, SyntaxKind::List).is_some());
+}
+
+#[test]
+fn roman_numeral_does_not_interrupt_list_item() {
+    let input = "- The plan\n  did. not work\n";
+    let node = parse_blocks(input);
+    assert_eq!(
+        node.descendants()
+            .filter(|n| n.kind() == SyntaxKind::ListItem)
+            .count(),
+        1
+    );
+}
+
+#[test]
+fn marker_requires_following_space() {
+    let node = parse_blocks("-not a list\n");
+    assert!(find_first(&node, SyntaxKind::List).is_none());
+
+    let node = parse_blocks("1.5 is a number\n");
+    assert!(find_first(&node, SyntaxKind::List).is_none());
+}
+
+#[test]
+fn list_requires_blank_line_before() {
+    let input = "Some text\n- not a list\n";
+    assert_block_kinds(input, &[SyntaxKind::PARAGRAPH]);
+}
+
+#[test]
+fn list_may_directly_follow_other_blocks() {
+    assert_block_kinds(
+        "---\n* star\n2) two\n",
+        &[
+            SyntaxKind::HorizontalRule,
+            SyntaxKind::List,
+            SyntaxKind::List,
+        ],
+    );
+    assert_block_kinds(
+        "# Heading\n- item\n",
+        &[SyntaxKind::Heading, SyntaxKind::List],
+    );
+    assert_block_kinds(
+        "```\ncode\n```\n- item\n",
+        &[SyntaxKind::CodeBlock, SyntaxKind::List],
+    );
+}
+
+#[test]
+fn list_item_has_explicit_marker_tokens() {
+    let node = parse_blocks("  10.  Item\n");
+    let item = find_first(&node, SyntaxKind::ListItem).unwrap();
+    let tokens: Vec<_> = item
+        .children_with_tokens()
+        .filter_map(|el| el.into_token())
+        .map(|t| (t.kind(), t.text().to_string()))
+        .collect();
+    assert_eq!(
+        tokens,
+        &[
+            (SyntaxKind::WHITESPACE, "  ".to_string()),
+            (SyntaxKind::ListMarker, "10.".to_string()),
+            (SyntaxKind::WHITESPACE, "  ".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn continuation_and_lazy_lines() {
+    let input = "- first line\n  continued\nlazy line\n- second\n";
+    let node = parse_blocks(input);
+    let list = find_first(&node, SyntaxKind::List).unwrap();
+    let items = items(&list);
+    assert_eq!(items.len(), 2);
+
+    let paragraph = find_first(&items[0], SyntaxKind::PARAGRAPH).unwrap();
+    assert_eq!(
+        paragraph.text().to_string(),
+        "first line\ncontinued\nlazy line\n"
+    );
+}
+
+#[test]
+fn item_indentation_is_not_kept_in_the_tree() {
+    let input = "- first\n  second\n\n  third\n";
+    let node = parse_blocks(input);
+    let item = find_first(&node, SyntaxKind::ListItem).unwrap();
+    assert_eq!(item.text().to_string(), "- first\nsecond\n\nthird\n");
+}
+
+#[test]
+fn lazy_line_after_overindented_continuation() {
+    let input = "- item text\n    more text\nlazy text\n";
+    assert_block_kinds(input, &[SyntaxKind::List]);
+
+    let node = parse_blocks(input);
+    let paragraph = find_first(&node, SyntaxKind::PARAGRAPH).unwrap();
+    assert_eq!(
+        paragraph.text().to_string(),
+        "item text\n  more text\nlazy text\n"
+    );
+}
+
+#[test]
+fn continuation_paragraph_after_blank_line() {
+    let input = "1. First\n\n   Second paragraph\n2. Next\n";
+    let node = parse_blocks(input);
+    let list = find_first(&node, SyntaxKind::List).unwrap();
+    let items = items(&list);
+    assert_eq!(items.len(), 2);
+    assert_eq!(
+        item_content_kinds(&items[0]),
+        &[
+            SyntaxKind::PARAGRAPH,
+            SyntaxKind::BlankLine,
+            SyntaxKind::PARAGRAPH
+        ]
+    );
+}
+
+#[test]
+fn unindented_paragraph_after_blank_line_ends_list() {
+    let input = "- item\n\nNot in the list\n";
+    assert_block_kinds(
+        input,
+        &[
+            SyntaxKind::List,
+            SyntaxKind::BlankLine,
+            SyntaxKind::PARAGRAPH,
+        ],
+    );
+}
+
+#[test]
+fn nested_lists() {
+    let input = "- outer\n  - inner one\n  - inner two\n- outer two\n";
+    let node = parse_blocks(input);
+    let outer = find_first(&node, SyntaxKind::List).unwrap();
+    let items = items(&outer);
+    assert_eq!(items.len(), 2);
+    assert_eq!(
+        item_content_kinds(&items[0]),
+        &[SyntaxKind::PARAGRAPH, SyntaxKind::List]
+    );
+}
+
+#[test]
+fn mixed_nested_ordered_and_bullet_lists() {
+    let input = "1. one\n   - a\n   - b\n2. two\n   1) x\n";
+    let node = parse_blocks(input);
+    assert_eq!(list_markers(&node), &["1.", "-", "-", "2.", "1)"]);
+    let outer = find_first(&node, SyntaxKind::List).unwrap();
+    assert_eq!(items(&outer).len(), 2);
+}
+
+#[test]
+fn code_block_inside_list_item() {
+    let input = "- item\n\n  ```\n  code\n  ```\n";
+    let node = parse_blocks(input);
+    let item = find_first(&node, SyntaxKind::ListItem).unwrap();
+    assert_eq!(
+        item_content_kinds(&item),
+        &[
+            SyntaxKind::PARAGRAPH,
+            SyntaxKind::BlankLine,
+            SyntaxKind::CodeBlock
+        ]
+    );
+}
+
+#[test]
+fn tight_and_loose_lists() {
+    let node = parse_blocks("- a\n- b\n");
+    assert!(!is_loose_list(
+        &find_first(&node, SyntaxKind::List).unwrap()
+    ));
+
+    let node = parse_blocks("- a\n\n- b\n");
+    assert!(is_loose_list(&find_first(&node, SyntaxKind::List).unwrap()));
+
+    let node = parse_blocks("- a\n\n  more\n- b\n");
+    assert!(is_loose_list(&find_first(&node, SyntaxKind::List).unwrap()));
+}
+
+#[test]
+fn trailing_blank_lines_are_not_part_of_list() {
+    let input = "- a\n- b\n\n";
+    assert_block_kinds(input, &[SyntaxKind::List, SyntaxKind::BlankLine]);
+}
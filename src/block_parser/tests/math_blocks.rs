@@ -0,0 +1,86 @@
+use crate::block_parser::tests::helpers::{assert_block_kinds, find_first, parse_blocks};
+use crate::syntax::{SyntaxKind, SyntaxNode};
+
+fn get_math_content(node: &SyntaxNode) -> Option<String> {
+    find_first(node, SyntaxKind::MathContent).map(|n| n.text().to_string())
+}
+
+fn get_attribute(node: &SyntaxNode) -> Option<String> {
+    find_first(node, SyntaxKind::Attribute).map(|n| n.text().to_string())
+}
+
+#[test]
+fn parses_multiline_math_block() {
+    let input = "$$\nx = y\n$$\n";
+    assert_block_kinds(input, &[SyntaxKind::MathBlock]);
+
+    let node = parse_blocks(input);
+    assert_eq!(get_math_content(&node).unwrap(), "x = y");
+    assert!(get_attribute(&node).is_none());
+}
+
+#[test]
+fn parses_single_line_math_block() {
+    let input = "$$ E = mc^2 $$\n";
+    assert_block_kinds(input, &[SyntaxKind::MathBlock]);
+
+    let node = parse_blocks(input);
+    assert_eq!(get_math_content(&node).unwrap(), " E = mc^2 ");
+}
+
+#[test]
+fn parses_label_on_closing_line() {
+    let input = "$$\na + b\n$$ {#eq-sum}\n";
+    assert_block_kinds(input, &[SyntaxKind::MathBlock]);
+
+    let node = parse_blocks(input);
+    assert_eq!(get_math_content(&node).unwrap(), "a + b");
+    assert_eq!(get_attribute(&node).unwrap(), "{#eq-sum}");
+}
+
+#[test]
+fn parses_label_on_single_line_block() {
+    let node = parse_blocks("$$ x $${#eq-x}\n");
+    assert_eq!(get_attribute(&node).unwrap(), "{#eq-x}");
+}
+
+#[test]
+fn label_whitespace_is_lossless() {
+    for input in ["$$\nx\n$$  {#eq-x}\n", "$$\nx\n$$\t{#eq-x}\n"] {
+        let node = parse_blocks(input);
+        assert_eq!(get_attribute(&node).unwrap(), "{#eq-x}");
+        assert_eq!(node.text().to_string(), input);
+    }
+}
+
+#[test]
+fn parses_content_on_marker_lines() {
+    let node = parse_blocks("$$ a = b\nc = d $$\n");
+    assert_eq!(get_math_content(&node).unwrap(), " a = b\nc = d ");
+}
+
+#[test]
+fn math_block_interrupts_paragraph() {
+    let input = "Consider\n$$\nx^2\n$$\nwhere x is real.\n";
+    assert_block_kinds(
+        input,
+        &[
+            SyntaxKind::PARAGRAPH,
+            SyntaxKind::MathBlock,
+            SyntaxKind::PARAGRAPH,
+        ],
+    );
+}
+
+#[test]
+fn unclosed_math_block_is_paragraph() {
+    let input = "$$\nx = y\n\nmore\n";
+    let node = parse_blocks(input);
+    assert!(find_first(&node, SyntaxKind::MathBlock).is_none());
+}
+
+#[test]
+fn inline_display_math_stays_in_paragraph() {
+    let input = "We have $$x = y$$ in the text.\n";
+    assert_block_kinds(input, &[SyntaxKind::PARAGRAPH]);
+}
@@ -0,0 +1,342 @@
+use crate::block_parser::tests::helpers::{assert_block_kinds, find_first, parse_blocks};
+use crate::syntax::{SyntaxKind, SyntaxNode};
+
+fn cell_texts(node: &SyntaxNode, kind: SyntaxKind) -> Vec<String> {
+    node.descendants()
+        .filter(|n| n.kind() == kind)
+        .map(|n| n.text().to_string().trim().to_string())
+        .collect()
+}
+
+#[test]
+fn parses_pipe_table() {
+    let input = "| a | b |\n|---|:-:|\n| 1 | 2 |\n| 3 | 4 |\n";
+    assert_block_kinds(input, &[SyntaxKind::PipeTable]);
+
+    let node = parse_blocks(input);
+    let table = find_first(&node, SyntaxKind::PipeTable).unwrap();
+    assert_eq!(table.text().to_string(), input);
+
+    let header = find_first(&table, SyntaxKind::TableHeader).unwrap();
+    assert_eq!(cell_texts(&header, SyntaxKind::TableCell), &["a", "b"]);
+    assert_eq!(
+        cell_texts(&table, SyntaxKind::TableAlignment),
+        &["---", ":-:"]
+    );
+    let body_rows = table
+        .children()
+        .filter(|n| n.kind() == SyntaxKind::TableRow)
+        .count();
+    assert_eq!(body_rows, 2);
+}
+
+#[test]
+fn pipe_table_without_outer_pipes() {
+    let input = "fruit | price\n------|-----:\napple | 2.05\n";
+    assert_block_kinds(input, &[SyntaxKind::PipeTable]);
+
+    let node = parse_blocks(input);
+    assert_eq!(
+        cell_texts(&node, SyntaxKind::TableCell),
+        &["fruit", "price", "apple", "2.05"]
+    );
+}
+
+#[test]
+fn escaped_pipes_do_not_split_cells() {
+    let input = "| a \\| b | c |\n|---|---|\n";
+    let node = parse_blocks(input);
+    assert_eq!(cell_texts(&node, SyntaxKind::TableCell), &["a \\| b", "c"]);
+}
+
+#[test]
+fn pipes_in_code_spans_do_not_split_cells() {
+    let input = "| `a|b` | ``c`|`d`` | `e |\n|---|---|---|\n";
+    let node = parse_blocks(input);
+    assert_eq!(
+        cell_texts(&node, SyntaxKind::TableCell),
+        &["`a|b`", "``c`|`d``", "`e"]
+    );
+}
+
+#[test]
+fn pipe_table_with_caption() {
+    let input = "| a |\n|---|\n| 1 |\n\n: The caption {#tbl-one}\n\nAfter.\n";
+    assert_block_kinds(
+        input,
+        &[
+            SyntaxKind::PipeTable,
+            SyntaxKind::BlankLine,
+            SyntaxKind::PARAGRAPH,
+        ],
+    );
+
+    let node = parse_blocks(input);
+    let caption = find_first(&node, SyntaxKind::TableCaption).unwrap();
+    assert_eq!(caption.text().to_string(), ": The caption {#tbl-one}\n");
+    let attribute = find_first(&caption, SyntaxKind::Attribute).unwrap();
+    assert_eq!(attribute.text().to_string(), "{#tbl-one}");
+}
+
+#[test]
+fn mismatched_alignment_row_is_not_a_table() {
+    // Without a matching alignment row, the rows are a line block
+    assert_block_kinds(
+        "| a | b |\n|---|\n",
+        &[SyntaxKind::LineBlock, SyntaxKind::PARAGRAPH],
+    );
+    assert_block_kinds("| a | b |\n| c | d |\n", &[SyntaxKind::LineBlock]);
+}
+
+#[test]
+fn pipe_table_ends_at_blank_line() {
+    let input = "| a |\n|---|\n| 1 |\n\nText | here\n";
+    assert_block_kinds(
+        input,
+        &[
+            SyntaxKind::PipeTable,
+            SyntaxKind::BlankLine,
+            SyntaxKind::PARAGRAPH,
+        ],
+    );
+}
+
+#[test]
+fn parses_grid_table() {
+    let input = "+-----+-----+\n| a   | b   |\n+=====+=====+\n| 1   | - x |\n|     | - y |\n+-----+-----+\n";
+    assert_block_kinds(input, &[SyntaxKind::GridTable]);
+
+    let node = parse_blocks(input);
+    let table = find_first(&node, SyntaxKind::GridTable).unwrap();
+    assert_eq!(table.text().to_string(), input);
+    let kinds: Vec<_> = table.children().map(|n| n.kind()).collect();
+    assert_eq!(
+        kinds,
+        &[
+            SyntaxKind::GridTableBorder,
+            SyntaxKind::GridTableRow,
+            SyntaxKind::GridTableBorder,
+            SyntaxKind::GridTableRow,
+            SyntaxKind::GridTableRow,
+            SyntaxKind::GridTableBorder,
+        ]
+    );
+}
+
+#[test]
+fn grid_table_with_partial_border() {
+    let input = "+---+---+\n| a | b |\n+---+   +\n| c |   |\n+---+---+\n";
+    assert_block_kinds(input, &[SyntaxKind::GridTable]);
+
+    let node = parse_blocks(input);
+    let table = find_first(&node, SyntaxKind::GridTable).unwrap();
+    assert_eq!(
+        child_kinds(&table),
+        &[
+            SyntaxKind::GridTableBorder,
+            SyntaxKind::GridTableRow,
+            SyntaxKind::GridTableRow,
+            SyntaxKind::GridTableRow,
+            SyntaxKind::GridTableBorder,
+        ]
+    );
+}
+
+#[test]
+fn grid_table_with_caption() {
+    let input = "+---+\n| a |\n+---+\n\n: Caption\n";
+    assert_block_kinds(input, &[SyntaxKind::GridTable]);
+    let node = parse_blocks(input);
+    assert!(find_first(&node, SyntaxKind::TableCaption).is_some());
+}
+
+#[test]
+fn grid_table_must_end_with_border() {
+    let input = "+---+\n| a |\n+---+\n| b |\n\nText.\n";
+    let node = parse_blocks(input);
+    let table = find_first(&node, SyntaxKind::GridTable).unwrap();
+    assert_eq!(table.text().to_string(), "+---+\n| a |\n+---+\n");
+
+    assert_block_kinds("+---+\n| a |\n", &[SyntaxKind::PARAGRAPH]);
+}
+
+fn child_kinds(node: &SyntaxNode) -> Vec<SyntaxKind> {
+    node.children().map(|n| n.kind()).collect()
+}
+
+#[test]
+fn parses_simple_table() {
+    let input = "  Right     Left     Center\n-------     ------ ----------\n     12     12        12\n    123     123       123\n";
+    assert_block_kinds(input, &[SyntaxKind::SimpleTable]);
+
+    let node = parse_blocks(input);
+    let table = find_first(&node, SyntaxKind::SimpleTable).unwrap();
+    assert_eq!(table.text().to_string(), input);
+    assert_eq!(
+        child_kinds(&table),
+        &[
+            SyntaxKind::TableHeader,
+            SyntaxKind::TableSeparator,
+            SyntaxKind::TableRow,
+            SyntaxKind::TableRow,
+        ]
+    );
+}
+
+#[test]
+fn simple_table_with_closing_line_and_caption() {
+    let input = "a     b\n---   ---\n1     2\n---   ---\n\nTable: Numbers\n";
+    assert_block_kinds(input, &[SyntaxKind::SimpleTable]);
+
+    let node = parse_blocks(input);
+    let table = find_first(&node, SyntaxKind::SimpleTable).unwrap();
+    assert_eq!(table.text().to_string(), input);
+    assert_eq!(
+        child_kinds(&table),
+        &[
+            SyntaxKind::TableHeader,
+            SyntaxKind::TableSeparator,
+            SyntaxKind::TableRow,
+            SyntaxKind::TableSeparator,
+            SyntaxKind::BlankLine,
+            SyntaxKind::TableCaption,
+        ]
+    );
+}
+
+#[test]
+fn headerless_simple_table() {
+    let input =
+        "-------     ------\n     12     12\n    123     123\n-------     ------\n\nAfter.\n";
+    assert_block_kinds(
+        input,
+        &[
+            SyntaxKind::SimpleTable,
+            SyntaxKind::BlankLine,
+            SyntaxKind::PARAGRAPH,
+        ],
+    );
+
+    let node = parse_blocks(input);
+    let table = find_first(&node, SyntaxKind::SimpleTable).unwrap();
+    assert_eq!(
+        child_kinds(&table),
+        &[
+            SyntaxKind::TableSeparator,
+            SyntaxKind::TableRow,
+            SyntaxKind::TableRow,
+            SyntaxKind::TableSeparator,
+        ]
+    );
+}
+
+#[test]
+fn parses_multiline_table() {
+    let input = "\
+----------------------------
+ Centered   Left
+  Header    Aligned
+----------- ----------------
+   First    Example of a row
+            on two lines.
+
+  Second    Another row.
+----------------------------
+
+: Caption
+";
+    assert_block_kinds(input, &[SyntaxKind::MultilineTable]);
+
+    let node = parse_blocks(input);
+    let table = find_first(&node, SyntaxKind::MultilineTable).unwrap();
+    assert_eq!(table.text().to_string(), input);
+    assert_eq!(
+        child_kinds(&table),
+        &[
+            SyntaxKind::TableSeparator,
+            SyntaxKind::TableHeader,
+            SyntaxKind::TableSeparator,
+            SyntaxKind::TableRow,
+            SyntaxKind::BlankLine,
+            SyntaxKind::TableRow,
+            SyntaxKind::TableSeparator,
+            SyntaxKind::BlankLine,
+            SyntaxKind::TableCaption,
+        ]
+    );
+    let rows: Vec<_> = table
+        .children()
+        .filter(|n| n.kind() == SyntaxKind::TableRow)
+        .map(|n| n.text().to_string())
+        .collect();
+    assert_eq!(
+        rows,
+        &[
+            "   First    Example of a row\n            on two lines.\n",
+            "  Second    Another row.\n"
+        ]
+    );
+}
+
+#[test]
+fn headerless_multiline_table() {
+    let input = "----------- ----------\n   First    row\n            continued\n\n  Second    row\n----------- ----------\n";
+    assert_block_kinds(input, &[SyntaxKind::MultilineTable]);
+}
+
+#[test]
+fn multiline_table_with_blank_lines_is_lossless() {
+    let input = "Before.\n\n----------- ----------\n   First    row\n\n  Second    row\n\n  Third     row\n----------- ----------\n\nAfter.\n";
+    let node = parse_blocks(input);
+    assert!(find_first(&node, SyntaxKind::MultilineTable).is_some());
+    assert_eq!(node.text().to_string(), input);
+}
+
+#[test]
+fn caption_after_blank_line_is_lossless() {
+    for input in [
+        "| a |\n|---|\n| 1 |\n\n: Pipe caption\n\nAfter.\n",
+        "+---+\n| a |\n+---+\n\nTable: Grid caption\n",
+        "  a    b\n---  ---\n  1    2\n\n: Simple caption\n",
+    ] {
+        let node = parse_blocks(input);
+        assert!(find_first(&node, SyntaxKind::TableCaption).is_some());
+        assert_eq!(node.text().to_string(), input);
+    }
+}
+
+#[test]
+fn single_column_underline_is_not_a_table() {
+    // A setext heading, not a one-column simple table
+    let node = parse_blocks("Heading\n-------\nText\n");
+    assert!(find_first(&node, SyntaxKind::SimpleTable).is_none());
+}
+
+#[test]
+fn lone_dash_line_is_not_a_table() {
+    let node = parse_blocks("Text\n\n---\n\nMore text\n");
+    assert!(find_first(&node, SyntaxKind::SimpleTable).is_none());
+    assert!(find_first(&node, SyntaxKind::MultilineTable).is_none());
+
+    let node = parse_blocks("Text\n\n---\n---\n\nMore text\n");
+    assert!(find_first(&node, SyntaxKind::SimpleTable).is_none());
+}
+
+#[test]
+fn headerless_table_must_end_before_blank_line() {
+    let node = parse_blocks("Text\n\n------\nfoo\n------\nbar\n");
+    assert!(find_first(&node, SyntaxKind::SimpleTable).is_none());
+    assert!(find_first(&node, SyntaxKind::MultilineTable).is_none());
+}
+
+#[test]
+fn multiline_table_rows_cannot_contain_two_blank_lines() {
+    let node = parse_blocks("Text\n\n------\nfoo\n\n\nbar\n------\n");
+    assert!(find_first(&node, SyntaxKind::MultilineTable).is_none());
+}
+
+#[test]
+fn simple_table_does_not_interrupt_paragraph() {
+    let input = "Some text\nA     B\n---   ---\n1     2\n";
+    assert_block_kinds(input, &[SyntaxKind::PARAGRAPH]);
+}
@@ -0,0 +1,66 @@
+use crate::block_parser::tests::helpers::{assert_block_kinds, find_first, parse_blocks};
+use crate::syntax::SyntaxKind;
+
+#[test]
+fn parses_thematic_breaks() {
+    for marker in ["***", "---", "___", "- - -", " *  *  * ", "_____"] {
+        let input = format!("Text\n\n{marker}\n\nMore text\n");
+        assert_block_kinds(
+            &input,
+            &[
+                SyntaxKind::PARAGRAPH,
+                SyntaxKind::BlankLine,
+                SyntaxKind::HorizontalRule,
+                SyntaxKind::BlankLine,
+                SyntaxKind::PARAGRAPH,
+            ],
+        );
+    }
+}
+
+#[test]
+fn thematic_break_is_lossless() {
+    let input = "  * * *  \n";
+    let node = parse_blocks(input);
+    let rule = find_first(&node, SyntaxKind::HorizontalRule).unwrap();
+    assert_eq!(rule.text().to_string(), input);
+}
+
+#[test]
+fn not_thematic_breaks() {
+    for input in ["**\n", "*-*\n", "    ***\n", "***a\n", "_ _\n"] {
+        let node = parse_blocks(input);
+        assert!(
+            find_first(&node, SyntaxKind::HorizontalRule).is_none(),
+            "{input:?}"
+        );
+    }
+}
+
+#[test]
+fn frontmatter_wins_over_thematic_break() {
+    let node = parse_blocks("---\ntitle: hi\n---\n");
+    assert!(find_first(&node, SyntaxKind::FRONTMATTER).is_some());
+    assert!(find_first(&node, SyntaxKind::HorizontalRule).is_none());
+}
+
+#[test]
+fn setext_underline_wins_over_thematic_break() {
+    assert_block_kinds("Title\n---\n", &[SyntaxKind::Heading]);
+}
+
+#[test]
+fn thematic_break_is_not_a_list() {
+    assert_block_kinds("* * *\n", &[SyntaxKind::HorizontalRule]);
+    assert_block_kinds("- - -\n", &[SyntaxKind::HorizontalRule]);
+}
+
+#[test]
+fn thematic_break_does_not_interrupt_paragraph() {
+    assert_block_kinds("foo\nbar\n***\n", &[SyntaxKind::PARAGRAPH]);
+}
+
+#[test]
+fn dash_line_around_table_rows_is_a_table() {
+    assert_block_kinds("-----\nrow\n-----\n", &[SyntaxKind::SimpleTable]);
+}
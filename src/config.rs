@@ -12,6 +12,11 @@ pub struct Config {
     pub line_width: usize,
     pub math_indent: usize,
     pub wrap: Option<WrapMode>,
+    pub grid_tables: GridTableMode,
+    pub heading_style: HeadingStyle,
+    pub thematic_break_style: ThematicBreakStyle,
+    pub indented_code_blocks: IndentedCodeMode,
+    pub link_reference_definitions: LinkReferenceMode,
 }
 
 impl Default for Config {
@@ -21,6 +26,11 @@ impl Default for Config {
             line_width: 80,
             math_indent: 0,
             wrap: Some(WrapMode::Reflow),
+            grid_tables: GridTableMode::Preserve,
+            heading_style: HeadingStyle::Atx,
+            thematic_break_style: ThematicBreakStyle::Preserve,
+            indented_code_blocks: IndentedCodeMode::Preserve,
+            link_reference_definitions: LinkReferenceMode::Preserve,
         }
     }
 }
@@ -46,6 +56,31 @@ impl ConfigBuilder {
         self
     }
 
+    pub fn grid_tables(mut self, mode: GridTableMode) -> Self {
+        self.config.grid_tables = mode;
+        self
+    }
+
+    pub fn heading_style(mut self, style: HeadingStyle) -> Self {
+        self.config.heading_style = style;
+        self
+    }
+
+    pub fn thematic_break_style(mut self, style: ThematicBreakStyle) -> Self {
+        self.config.thematic_break_style = style;
+        self
+    }
+
+    pub fn indented_code_blocks(mut self, mode: IndentedCodeMode) -> Self {
+        self.config.indented_code_blocks = mode;
+        self
+    }
+
+    pub fn link_reference_definitions(mut self, mode: LinkReferenceMode) -> Self {
+        self.config.link_reference_definitions = mode;
+        self
+    }
+
     pub fn build(self) -> Config {
         self.config
     }
@@ -58,6 +93,69 @@ pub enum WrapMode {
     Reflow,
 }
 
+/// How grid tables are formatted.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum GridTableMode {
+    /// Keep grid tables exactly as written.
+    Preserve,
+    /// Redraw the borders so that every column is as wide as its widest
+    /// cell.
+    Redraw,
+}
+
+/// How headings are written.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum HeadingStyle {
+    /// Write all headings as ATX headings (`# Title`).
+    Atx,
+    /// Write level 1 and 2 headings as setext headings (`Title` underlined
+    /// with `=` or `-`), and deeper headings as ATX headings.
+    Setext,
+    /// Keep the style each heading is written in.
+    Preserve,
+}
+
+/// How thematic breaks are written.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum ThematicBreakStyle {
+    /// Keep the characters of each thematic break, without surrounding
+    /// whitespace.
+    Preserve,
+    /// `---`
+    Dashes,
+    /// `***`
+    Asterisks,
+    /// `___`
+    Underscores,
+}
+
+/// How indented code blocks are formatted.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum IndentedCodeMode {
+    /// Keep indented code blocks as written.
+    Preserve,
+    /// Convert indented code blocks into fenced code blocks.
+    Fenced,
+}
+
+/// Where link reference definitions (`[label]: url`) are written.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum LinkReferenceMode {
+    /// Keep each definition where it is.
+    Preserve,
+    /// Sort each group of consecutive definitions by label.
+    Sort,
+    /// Move top-level definitions to the end of the document, in the order
+    /// they appear. Definitions inside containers such as list items or
+    /// block quotes stay where they are.
+    Collect,
+}
+
 #[derive(Debug, Clone, Deserialize, PartialEq)]
 #[serde(rename_all = "kebab-case")]
 pub enum LineEnding {
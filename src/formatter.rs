@@ -1,26 +1,191 @@
-use crate::config::{Config, WrapMode};
+use crate::block_parser::{could_start_block, indent_width, is_loose_list, strip_indent};
+use crate::config::{
+    Config, HeadingStyle, IndentedCodeMode, LinkReferenceMode, ThematicBreakStyle, WrapMode,
+};
+use crate::inline_parser::normalize_label;
 use crate::syntax::{SyntaxKind, SyntaxNode};
 
 use rowan::NodeOrToken;
+use textwrap::core::display_width;
 use textwrap::wrap_algorithms::WrapAlgorithm;
 
+mod tables;
+
 pub struct Formatter {
     output: String,
     config: Config,
 }
 
+/// Inline nodes whose content may be wrapped across lines. All other inline
+/// nodes are kept together on one line.
+fn is_breakable_inline(kind: SyntaxKind) -> bool {
+    matches!(
+        kind,
+        SyntaxKind::Emphasis
+            | SyntaxKind::Strong
+            | SyntaxKind::Link
+            | SyntaxKind::ImageLink
+            | SyntaxKind::LinkText
+            | SyntaxKind::BracketedSpan
+            | SyntaxKind::SpanContent
+            | SyntaxKind::InlineFootnote
+    )
+}
+
+/// Strip the four columns of indentation from a line of an indented code
+/// block. Blank lines may be indented less.
+fn strip_code_indent(line: &str) -> &str {
+    let mut col = 0;
+    for (i, c) in line.char_indices() {
+        if col >= 4 {
+            return &line[i..];
+        }
+        match c {
+            ' ' => col += 1,
+            '\t' => col += 4 - col % 4,
+            _ => return &line[i..],
+        }
+    }
+    ""
+}
+
 fn is_block_element(kind: SyntaxKind) -> bool {
     matches!(
         kind,
         SyntaxKind::PARAGRAPH
             | SyntaxKind::List
+            | SyntaxKind::DefinitionList
+            | SyntaxKind::LineBlock
+            | SyntaxKind::LinkReferenceDefinition
             | SyntaxKind::BlockQuote
             | SyntaxKind::MathBlock
             | SyntaxKind::CodeBlock
+            | SyntaxKind::CodeCell
+            | SyntaxKind::IndentedCodeBlock
             | SyntaxKind::SimpleTable
+            | SyntaxKind::MultilineTable
+            | SyntaxKind::PipeTable
+            | SyntaxKind::GridTable
+            | SyntaxKind::FootnoteDefinition
+            | SyntaxKind::HorizontalRule
+            | SyntaxKind::HtmlBlock
+            | SyntaxKind::Comment
+            | SyntaxKind::LatexEnvironment
+            | SyntaxKind::LatexCommand
     )
 }
 
+/// Normalized text of an attribute block: the identifier first, then the
+/// classes and then the key-value pairs, separated by single spaces.
+fn attribute_text(node: &SyntaxNode) -> String {
+    let mut ids = Vec::new();
+    let mut classes = Vec::new();
+    let mut pairs = Vec::new();
+    for el in node.children_with_tokens() {
+        match el.kind() {
+            SyntaxKind::AttributeId => ids.push(el.to_string()),
+            SyntaxKind::AttributeClass => classes.push(el.to_string()),
+            SyntaxKind::AttributeKeyValue => pairs.push(el.to_string()),
+            _ => {}
+        }
+    }
+    let parts: Vec<String> = ids.into_iter().chain(classes).chain(pairs).collect();
+    format!("{{{}}}", parts.join(" "))
+}
+
+/// Normalized text of a link reference definition, on a single line:
+/// `[label]: url "title" {attributes}`.
+fn link_reference_text(node: &SyntaxNode) -> String {
+    let mut text = String::new();
+    for el in node.children_with_tokens() {
+        match el.kind() {
+            SyntaxKind::LinkReferenceLabel => {
+                text.push_str(&el.to_string());
+                text.push(':');
+            }
+            SyntaxKind::LinkUrl | SyntaxKind::LinkTitle => {
+                text.push(' ');
+                text.push_str(&el.to_string());
+            }
+            SyntaxKind::Attribute => {
+                if let Some(attribute) = el.as_node() {
+                    text.push(' ');
+                    text.push_str(&attribute_text(attribute));
+                }
+            }
+            _ => {}
+        }
+    }
+    text
+}
+
+/// Normalized label of a link reference definition, for sorting.
+fn link_reference_label(node: &SyntaxNode) -> String {
+    node.children_with_tokens()
+        .find(|el| el.kind() == SyntaxKind::LinkReferenceLabel)
+        .map(|el| {
+            let label = el.to_string();
+            normalize_label(&label[1..label.len() - 1])
+        })
+        .unwrap_or_default()
+}
+
+/// Text of an inline HTML tag with runs of whitespace outside quoted
+/// attribute values collapsed, so that a tag spanning lines reflows as if
+/// it had been written on one line.
+fn html_tag_text(node: &SyntaxNode) -> String {
+    let mut text = String::new();
+    let mut quote = None;
+    for c in node.text().to_string().chars() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if c == '"' || c == '\'' => quote = Some(c),
+            None if c.is_whitespace() => {
+                if !text.ends_with(' ') {
+                    text.push(' ');
+                }
+                continue;
+            }
+            None => {}
+        }
+        text.push(if c == '\n' { ' ' } else { c });
+    }
+    text
+}
+
+/// Normalized text of a citation: the items of a bracketed group are
+/// separated by `; ` and runs of whitespace within them collapsed. Keys
+/// are kept as they are.
+fn citation_text(node: &SyntaxNode) -> String {
+    let items: Vec<String> = node
+        .children()
+        .filter(|n| n.kind() == SyntaxKind::CitationItem)
+        .map(|item| {
+            let mut text = String::new();
+            for token in item.children_with_tokens().filter_map(|el| el.into_token()) {
+                if token.kind() == SyntaxKind::CitationKey {
+                    text.push_str(token.text());
+                    continue;
+                }
+                for c in token.text().chars() {
+                    if !c.is_whitespace() {
+                        text.push(c);
+                    } else if !text.ends_with(' ') {
+                        text.push(' ');
+                    }
+                }
+            }
+            text.trim().to_string()
+        })
+        .collect();
+    if node.first_token().is_some_and(|t| t.text() == "[") {
+        format!("[{}]", items.join("; "))
+    } else {
+        items.concat()
+    }
+}
+
 impl Formatter {
     pub fn new(config: Config) -> Self {
         Self {
@@ -29,17 +194,21 @@ impl Formatter {
         }
     }
 
+    /// Split the inline content of `node` into words for wrapping. The words
+    /// are grouped into segments that are separated by hard line breaks.
     fn build_words<'a>(
         &self,
         node: &SyntaxNode,
         arena: &'a mut Vec<Box<str>>,
-    ) -> Vec<textwrap::core::Word<'a>> {
+    ) -> Vec<Vec<textwrap::core::Word<'a>>> {
         struct Builder<'a> {
             arena: &'a mut Vec<Box<str>>,
             piece_idx: Vec<usize>,
             whitespace_after: Vec<bool>,
             last_piece_pos: Option<usize>,
             pending_space: bool,
+            // Piece positions that are followed by a hard line break
+            breaks_after: Vec<usize>,
         }
 
         impl<'a> Builder<'a> {
@@ -50,6 +219,7 @@ impl Formatter {
                     whitespace_after: Vec::new(),
                     last_piece_pos: None,
                     pending_space: false,
+                    breaks_after: Vec::new(),
                 }
             }
 
@@ -97,65 +267,204 @@ impl Formatter {
             }
         }
 
-        let mut b = Builder::new(arena);
-
-        for el in node.children_with_tokens() {
-            match el {
-                NodeOrToken::Token(t) => match t.kind() {
-                    SyntaxKind::WHITESPACE | SyntaxKind::NEWLINE | SyntaxKind::BlankLine => {
-                        b.pending_space = true;
+        fn walk(b: &mut Builder<'_>, node: &SyntaxNode) {
+            for el in node.children_with_tokens() {
+                match el {
+                    NodeOrToken::Token(t) => match t.kind() {
+                        SyntaxKind::WHITESPACE | SyntaxKind::NEWLINE | SyntaxKind::BlankLine => {
+                            b.pending_space = true;
+                        }
+                        SyntaxKind::HardLineBreak => {
+                            // Keep the break marker on the line it ends
+                            b.pending_space = false;
+                            b.push_piece(t.text());
+                            if let Some(pos) = b.last_piece_pos {
+                                b.breaks_after.push(pos);
+                            }
+                        }
+                        _ => {
+                            b.push_piece(t.text());
+                        }
+                    },
+                    NodeOrToken::Node(n) if is_breakable_inline(n.kind()) => walk(b, &n),
+                    NodeOrToken::Node(n) if n.kind() == SyntaxKind::Citation => {
+                        b.push_piece(&citation_text(&n));
+                    }
+                    NodeOrToken::Node(n) if n.kind() == SyntaxKind::InlineHtml => {
+                        b.push_piece(&html_tag_text(&n));
                     }
-                    _ => {
-                        b.push_piece(t.text());
+                    NodeOrToken::Node(n) => {
+                        // Atomic inline elements are never broken; newlines
+                        // inside them are equivalent to spaces
+                        let text = n.text().to_string().replace('\n', " ");
+                        b.push_piece(&text);
                     }
-                },
-                NodeOrToken::Node(n) => {
-                    let text = n.text().to_string();
-                    b.push_piece(&text);
                 }
             }
         }
 
-        let mut words: Vec<textwrap::core::Word<'a>> = Vec::with_capacity(b.piece_idx.len());
+        let mut b = Builder::new(arena);
+        walk(&mut b, node);
+
+        // Each word as its arena index, whether whitespace follows it and
+        // whether a hard line break follows it. A word that would start a
+        // block at the beginning of a line, such as `-` or `1.`, is glued to
+        // the word before it so that wrapping never puts it there.
+        let mut pieces: Vec<(usize, bool, bool)> = Vec::new();
         for (i, &idx) in b.piece_idx.iter().enumerate() {
-            let s: &'a str = &b.arena[idx];
-            let mut w = textwrap::core::Word::from(s);
-            if b.whitespace_after.get(i).copied().unwrap_or(false) {
+            let whitespace = b.whitespace_after[i];
+            let hard_break = b.breaks_after.contains(&i);
+            match pieces.last_mut() {
+                Some(prev) if prev.1 && !prev.2 && could_start_block(&b.arena[idx]) => {
+                    let combined = format!("{} {}", b.arena[prev.0], b.arena[idx]);
+                    b.arena.push(combined.into_boxed_str());
+                    *prev = (b.arena.len() - 1, whitespace, hard_break);
+                }
+                _ => pieces.push((idx, whitespace, hard_break)),
+            }
+        }
+
+        let arena: &'a [Box<str>] = b.arena;
+        let mut segments = vec![Vec::new()];
+        for (i, &(idx, whitespace, hard_break)) in pieces.iter().enumerate() {
+            let mut w = textwrap::core::Word::from(&arena[idx]);
+            // A trailing-space hard break keeps its spaces as whitespace
+            if whitespace && !hard_break {
                 w.whitespace = " ";
             }
-            words.push(w);
+            segments.last_mut().unwrap().push(w);
+            if hard_break && i + 1 < pieces.len() {
+                segments.push(Vec::new());
+            }
         }
-        words
+        segments
     }
 
     fn wrapped_lines_for_paragraph(&self, node: &SyntaxNode, width: usize) -> Vec<String> {
         let mut arena: Vec<Box<str>> = Vec::new();
-        let words = self.build_words(node, &mut arena);
+        let segments = self.build_words(node, &mut arena);
 
         let algo = WrapAlgorithm::new();
         let line_widths = [width];
-        let lines = algo.wrap(&words, &line_widths);
-
-        let mut out_lines = Vec::with_capacity(lines.len());
-
-        for line in lines {
-            let mut acc = String::new();
-            for (i, w) in line.iter().enumerate() {
-                acc.push_str(w.word);
-                if i + 1 < line.len() {
-                    acc.push_str(w.whitespace);
-                } else {
-                    acc.push_str(w.penalty);
+        let mut out_lines = Vec::new();
+
+        for (seg_idx, words) in segments.iter().enumerate() {
+            let ends_with_break = seg_idx + 1 < segments.len();
+            for line in algo.wrap(words, &line_widths) {
+                let mut acc = String::new();
+                for (i, w) in line.iter().enumerate() {
+                    acc.push_str(w.word);
+                    if i + 1 < line.len() {
+                        acc.push_str(w.whitespace);
+                    } else {
+                        acc.push_str(w.penalty);
+                    }
                 }
+                out_lines.push(acc);
+            }
+            if ends_with_break
+                && let (Some(last_line), Some(last_word)) = (out_lines.last_mut(), words.last())
+            {
+                // Trailing spaces of a hard line break are significant
+                last_line.push_str(last_word.whitespace);
             }
-            out_lines.push(acc);
         }
         out_lines
     }
 
+    /// Format the blocks in `content` with `marker` in front of the first
+    /// line and the remaining lines indented by `hanging` columns, as for
+    /// list items and footnote definitions.
+    fn format_with_marker(
+        &mut self,
+        indent: usize,
+        marker: &str,
+        hanging: usize,
+        content: Option<SyntaxNode>,
+    ) {
+        // The first line must fit after the marker as well
+        let prefix = hanging.max(indent + marker.len() + 1);
+        let body = content
+            .map(|c| {
+                let width = self.config.line_width.saturating_sub(prefix);
+                self.format_nested(c.children(), width)
+            })
+            .unwrap_or_default();
+
+        self.output.push_str(&" ".repeat(indent));
+        self.output.push_str(marker);
+
+        for (i, line) in body.lines().enumerate() {
+            if i > 0 {
+                self.output.push('\n');
+                if !line.trim().is_empty() {
+                    self.output.push_str(&" ".repeat(hanging));
+                }
+            } else if !line.trim().is_empty() {
+                self.output.push(' ');
+            }
+            if !line.trim().is_empty() {
+                self.output.push_str(line);
+            }
+        }
+        self.output.push('\n');
+    }
+
+    /// Format a sequence of block nodes on their own, as if they formed a
+    /// document of the given width. Used for container content that is
+    /// indented as a whole, such as list items.
+    fn format_nested(&self, nodes: impl Iterator<Item = SyntaxNode>, width: usize) -> String {
+        let config = Config {
+            line_width: width,
+            ..self.config.clone()
+        };
+        let mut formatter = Formatter::new(config);
+        for node in nodes {
+            formatter.format_node(&node, 0);
+        }
+        formatter.output
+    }
+
     pub fn format(mut self, node: &SyntaxNode) -> String {
         self.format_node(node, 0);
+        if self.config.link_reference_definitions == LinkReferenceMode::Collect {
+            self.append_link_references(node);
+        }
+        self.output
+    }
+
+    /// Whether `node` is a link reference definition that is moved to the
+    /// end of the document. Only top-level definitions are collected, so
+    /// that no container is left empty.
+    fn is_collected_link_reference(&self, node: &SyntaxNode) -> bool {
+        self.config.link_reference_definitions == LinkReferenceMode::Collect
+            && node.kind() == SyntaxKind::LinkReferenceDefinition
+            && node
+                .parent()
+                .is_some_and(|p| p.kind() == SyntaxKind::DOCUMENT)
+    }
+
+    /// Write all collected link reference definitions in `node` at the end
+    /// of the output, separated from the rest by a blank line.
+    fn append_link_references(&mut self, node: &SyntaxNode) {
+        let definitions: Vec<String> = node
+            .descendants()
+            .filter(|n| self.is_collected_link_reference(n))
+            .map(|n| link_reference_text(&n))
+            .collect();
+        if definitions.is_empty() {
+            return;
+        }
+
         self.output
+            .truncate(self.output.trim_end_matches('\n').len());
+        if !self.output.is_empty() {
+            self.output.push_str("\n\n");
+        }
+        for definition in definitions {
+            self.output.push_str(&definition);
+            self.output.push('\n');
+        }
     }
 
     fn format_node(&mut self, node: &SyntaxNode, indent: usize) {
@@ -186,6 +495,10 @@ impl Formatter {
                 let mut level = 1;
                 let mut content = String::new();
                 let mut saw_content = false;
+                let mut attribute = None;
+                let setext = node
+                    .children()
+                    .any(|n| n.kind() == SyntaxKind::SetextHeadingUnderline);
 
                 for child in node.children() {
                     match child.kind() {
@@ -195,19 +508,22 @@ impl Formatter {
                         }
                         SyntaxKind::SetextHeadingUnderline => {
                             let t = child.text().to_string();
-                            if t.chars().all(|c| c == '=') {
+                            if t.trim().starts_with('=') {
                                 level = 1;
                             } else {
                                 level = 2;
                             }
                         }
+                        SyntaxKind::Attribute => {
+                            attribute = Some(attribute_text(&child));
+                        }
                         SyntaxKind::HeadingContent => {
                             let mut t = child.text().to_string();
                             // Trim trailing spaces and closing hashes in ATX form
                             t = t.trim_end().to_string();
                             // Remove trailing " ###" if present
                             let trimmed_hash = t.trim_end_matches('#').to_string();
-                            if trimmed_hash.len() != t.len() {
+                            if !setext && trimmed_hash.len() != t.len() {
                                 t = trimmed_hash.trim_end().to_string();
                             }
                             // Normalize internal newlines
@@ -220,10 +536,34 @@ impl Formatter {
                 if !saw_content {
                     content = node.text().to_string();
                 }
-                self.output.push_str(&"#".repeat(level));
-                self.output.push(' ');
-                self.output.push_str(&content);
-                self.output.push('\n');
+                // The setext underline spans the heading text only
+                let text_width = display_width(&content);
+                if let Some(attribute) = attribute {
+                    if !content.is_empty() {
+                        content.push(' ');
+                    }
+                    content.push_str(&attribute);
+                }
+
+                let use_setext = level <= 2
+                    && !content.is_empty()
+                    && match self.config.heading_style {
+                        HeadingStyle::Atx => false,
+                        HeadingStyle::Setext => true,
+                        HeadingStyle::Preserve => setext,
+                    };
+                if use_setext {
+                    let underline = if level == 1 { "=" } else { "-" };
+                    self.output.push_str(&content);
+                    self.output.push('\n');
+                    self.output.push_str(&underline.repeat(text_width.max(3)));
+                    self.output.push('\n');
+                } else {
+                    self.output.push_str(&"#".repeat(level));
+                    self.output.push(' ');
+                    self.output.push_str(&content);
+                    self.output.push('\n');
+                }
 
                 if let Some(next) = node.next_sibling()
                     && is_block_element(next.kind())
@@ -233,6 +573,33 @@ impl Formatter {
                 }
             }
 
+            SyntaxKind::HorizontalRule => {
+                let marker = node
+                    .children_with_tokens()
+                    .filter_map(|el| el.into_token())
+                    .find(|t| t.kind() == SyntaxKind::HorizontalRule)
+                    .map(|t| t.text().to_string())
+                    .unwrap_or_default();
+                let marker = match self.config.thematic_break_style {
+                    ThematicBreakStyle::Preserve => marker,
+                    ThematicBreakStyle::Dashes => "---".to_string(),
+                    ThematicBreakStyle::Asterisks => "***".to_string(),
+                    ThematicBreakStyle::Underscores => "___".to_string(),
+                };
+                self.output.push_str(&" ".repeat(indent));
+                self.output.push_str(&marker);
+                self.output.push('\n');
+
+                // Keep the break from turning the next line into a setext
+                // heading or frontmatter
+                if let Some(next) = node.next_sibling()
+                    && next.kind() != SyntaxKind::BlankLine
+                    && !self.output.ends_with("\n\n")
+                {
+                    self.output.push('\n');
+                }
+            }
+
             SyntaxKind::LatexEnvironment => {
                 // Output the environment exactly as written
                 let text = node.text().to_string();
@@ -258,54 +625,20 @@ impl Formatter {
             }
 
             SyntaxKind::BlockQuote => {
-                // Determine nesting depth by counting ancestor BlockQuote nodes (including self)
-                let mut depth = 0usize;
-                let mut cur = Some(node.clone());
-                while let Some(n) = cur {
-                    if n.kind() == SyntaxKind::BlockQuote {
-                        depth += 1;
-                    }
-                    cur = n.parent();
-                }
-
-                // Prefixes for quoted content and blank quoted lines
-                let content_prefix = "> ".repeat(depth); // includes trailing space
-                let blank_prefix = content_prefix.trim_end(); // no trailing space
-
-                // Format children (paragraphs, blank lines) with proper > prefix per depth
-                let wrap_mode = self.config.wrap.clone().unwrap_or(WrapMode::Reflow);
-
-                for child in node.children() {
-                    match child.kind() {
-                        SyntaxKind::PARAGRAPH => match wrap_mode {
-                            WrapMode::Preserve => {
-                                let text = child.text().to_string();
-                                for line in text.lines() {
-                                    self.output.push_str(&content_prefix);
-                                    self.output.push_str(line);
-                                    self.output.push('\n');
-                                }
-                            }
-                            WrapMode::Reflow => {
-                                let width =
-                                    self.config.line_width.saturating_sub(content_prefix.len());
-                                let lines = self.wrapped_lines_for_paragraph(&child, width);
-                                for line in lines {
-                                    self.output.push_str(&content_prefix);
-                                    self.output.push_str(&line);
-                                    self.output.push('\n');
-                                }
-                            }
-                        },
-                        SyntaxKind::BlankLine => {
-                            self.output.push_str(blank_prefix);
-                            self.output.push('\n');
-                        }
-                        _ => {
-                            // Handle other content within block quotes
-                            self.format_node(&child, indent);
-                        }
+                // The content is formatted on its own and every line
+                // prefixed with `> `, or just `>` if blank. Nested quotes
+                // thereby get one more marker per level.
+                let width = self.config.line_width.saturating_sub(2);
+                let body = self.format_nested(node.children(), width);
+                for line in body.lines() {
+                    self.output.push_str(&" ".repeat(indent));
+                    if line.is_empty() {
+                        self.output.push('>');
+                    } else {
+                        self.output.push_str("> ");
+                        self.output.push_str(line);
                     }
+                    self.output.push('\n');
                 }
             }
 
@@ -337,16 +670,27 @@ impl Formatter {
             }
 
             SyntaxKind::List => {
-                for child in node.children() {
-                    self.format_node(&child, indent);
+                // Loose lists get exactly one blank line between items,
+                // tight lists none
+                let loose = is_loose_list(node);
+                let items = node.children().filter(|n| n.kind() == SyntaxKind::ListItem);
+                for (i, item) in items.enumerate() {
+                    if i > 0 && loose {
+                        self.output.push('\n');
+                    }
+                    self.format_node(&item, indent);
                 }
             }
 
-            SyntaxKind::SimpleTable => {
+            SyntaxKind::SimpleTable | SyntaxKind::MultilineTable => {
                 // Preserve table as-is, including line breaks and spacing
                 self.output.push_str(&node.text().to_string());
             }
 
+            SyntaxKind::PipeTable => self.format_pipe_table(node, indent),
+
+            SyntaxKind::GridTable => self.format_grid_table(node, indent),
+
             SyntaxKind::InlineMath => {
                 for child in node.children() {
                     self.output.push_str(&child.text().to_string());
@@ -354,80 +698,81 @@ impl Formatter {
             }
 
             SyntaxKind::ListItem => {
-                // Compute indent and marker from leading tokens
                 let mut marker = String::new();
-                let mut local_indent = 0;
-                let mut content_start = false;
+                let mut content = None;
 
                 for el in node.children_with_tokens() {
                     match el {
-                        NodeOrToken::Token(t) => match t.kind() {
-                            SyntaxKind::WHITESPACE => {
-                                if !content_start {
-                                    local_indent += t.text().len();
-                                }
-                            }
-                            SyntaxKind::ListMarker => {
-                                marker = t.text().to_string();
-                                content_start = true;
-                            }
-                            _ => {
-                                content_start = true;
-                            }
-                        },
-                        _ => {
-                            content_start = true;
+                        NodeOrToken::Token(t) if t.kind() == SyntaxKind::ListMarker => {
+                            marker = t.text().to_string();
+                        }
+                        NodeOrToken::Node(n) if n.kind() == SyntaxKind::ItemContent => {
+                            content = Some(n);
                         }
+                        _ => {}
                     }
                 }
 
-                let total_indent = indent + local_indent;
-                let hanging = marker.len() + 1 + total_indent; // +1 for the space after marker
-                let available_width = self.config.line_width.saturating_sub(hanging);
-
-                // Build words from the whole node, then drop the leading marker word
-                let mut arena: Vec<Box<str>> = Vec::new();
-                let mut words = self.build_words(node, &mut arena);
-                if let Some(first) = words.first()
-                    && first.word == marker
-                {
-                    // Remove the marker; we will print it ourselves with a following space
-                    words.remove(0);
-                }
-
-                let algo = WrapAlgorithm::new();
-                let line_widths = [available_width];
-                let lines = algo.wrap(&words, &line_widths);
+                // Continuation lines hang under the first character after
+                // the marker and its following space
+                let hanging = indent + marker.len() + 1;
+                self.format_with_marker(indent, &marker, hanging, content);
+            }
 
-                for (i, line) in lines.iter().enumerate() {
-                    if i == 0 {
-                        self.output.push_str(&" ".repeat(total_indent));
-                        self.output.push_str(&marker);
-                        self.output.push(' ');
-                    } else {
-                        // Hanging indent includes marker + one space
-                        self.output
-                            .push_str(&" ".repeat(total_indent + marker.len() + 1));
-                    }
-                    for (j, w) in line.iter().enumerate() {
-                        self.output.push_str(w.word);
-                        if j + 1 < line.len() {
-                            self.output.push_str(w.whitespace);
-                        } else {
-                            self.output.push_str(w.penalty);
-                        }
+            SyntaxKind::DefinitionList => {
+                // Items are always separated by exactly one blank line
+                let items = node
+                    .children()
+                    .filter(|n| n.kind() == SyntaxKind::DefinitionItem);
+                for (i, item) in items.enumerate() {
+                    if i > 0 {
+                        self.output.push('\n');
                     }
-                    self.output.push('\n');
+                    self.format_node(&item, indent);
                 }
+            }
 
-                // Format nested lists inside this list item with increased indent
+            SyntaxKind::DefinitionItem => {
+                // A blank line before a definition makes it loose, so it is
+                // kept; the marker is normalized to `:` followed by spaces up
+                // to the fourth column, where the content hangs
+                let mut blank_before = false;
                 for child in node.children() {
-                    if child.kind() == SyntaxKind::List {
-                        self.format_node(&child, total_indent + 2);
+                    match child.kind() {
+                        SyntaxKind::Term => {
+                            self.output.push_str(&" ".repeat(indent));
+                            self.output.push_str(child.text().to_string().trim());
+                            self.output.push('\n');
+                        }
+                        SyntaxKind::BlankLine => blank_before = true,
+                        SyntaxKind::Definition => {
+                            if blank_before {
+                                self.output.push('\n');
+                            }
+                            self.format_with_marker(indent, ":  ", indent + 4, Some(child));
+                            blank_before = false;
+                        }
+                        _ => {}
                     }
                 }
             }
 
+            SyntaxKind::FootnoteDefinition => {
+                let marker = node
+                    .children_with_tokens()
+                    .filter_map(|el| el.into_token())
+                    .find(|t| t.kind() == SyntaxKind::FootnoteDefinitionMarker)
+                    .map(|t| t.text().to_string())
+                    .unwrap_or_default();
+                let content = node
+                    .children()
+                    .find(|n| n.kind() == SyntaxKind::FootnoteContent);
+
+                // Continuation lines are indented by four spaces, as Pandoc
+                // requires for further paragraphs of the note
+                self.format_with_marker(indent, &marker, indent + 4, content);
+            }
+
             SyntaxKind::FencedDiv => {
                 let mut fence_open = None;
                 let mut fence_close = None;
@@ -473,11 +818,6 @@ impl Formatter {
                 }
             }
 
-            SyntaxKind::InlineMathMarker => {
-                // Output inline math as $...$ or $$...$$ (on the same line)
-                self.output.push_str(node.text().to_string().trim());
-            }
-
             SyntaxKind::MathBlock => {
                 let mut label = None;
                 let mut math_content = None;
@@ -496,10 +836,21 @@ impl Formatter {
                 self.output.push_str("$$\n");
                 // Math content
                 if let Some(content) = math_content {
+                    // Replace the common indentation of the content with
+                    // the configured math indentation
                     let math_indent = self.config.math_indent;
-                    for line in content.trim().lines() {
-                        self.output.push_str(&" ".repeat(math_indent));
-                        self.output.push_str(line.trim_end());
+                    let lines: Vec<&str> = content.trim_matches('\n').lines().collect();
+                    let common = lines
+                        .iter()
+                        .filter(|l| !l.trim().is_empty())
+                        .map(|l| indent_width(l))
+                        .min()
+                        .unwrap_or(0);
+                    for line in lines {
+                        if !line.trim().is_empty() {
+                            self.output.push_str(&" ".repeat(math_indent));
+                            self.output.push_str(strip_indent(line, common).trim_end());
+                        }
                         self.output.push('\n');
                     }
                 }
@@ -512,7 +863,10 @@ impl Formatter {
                 self.output.push('\n');
             }
 
-            SyntaxKind::CodeBlock | SyntaxKind::FRONTMATTER => {
+            SyntaxKind::CodeBlock
+            | SyntaxKind::CodeCell
+            | SyntaxKind::FRONTMATTER
+            | SyntaxKind::HtmlBlock => {
                 // Preserve these blocks as-is
                 let text = node.text().to_string();
                 self.output.push_str(&text);
@@ -522,11 +876,85 @@ impl Formatter {
                 }
             }
 
+            SyntaxKind::LineBlock => {
+                // Line breaks and leading spaces are significant, so only
+                // trailing whitespace is removed
+                for line in node.text().to_string().lines() {
+                    self.output.push_str(line.trim_end());
+                    self.output.push('\n');
+                }
+            }
+
+            SyntaxKind::IndentedCodeBlock => match self.config.indented_code_blocks {
+                IndentedCodeMode::Preserve => self.output.push_str(&node.text().to_string()),
+                IndentedCodeMode::Fenced => {
+                    let text = node.text().to_string();
+                    let lines: Vec<&str> = text.lines().map(strip_code_indent).collect();
+                    // The fence must be longer than any line of the code
+                    // that could close it, which may be indented by up to
+                    // three spaces
+                    let longest = lines
+                        .iter()
+                        .map(|l| {
+                            let code = l.trim_start_matches(' ');
+                            if l.len() - code.len() > 3 {
+                                0
+                            } else {
+                                code.chars().take_while(|&c| c == '`').count()
+                            }
+                        })
+                        .max()
+                        .unwrap_or(0);
+                    let fence = "`".repeat((longest + 1).max(3));
+                    self.output.push_str(&fence);
+                    self.output.push('\n');
+                    for line in lines {
+                        self.output.push_str(line);
+                        self.output.push('\n');
+                    }
+                    self.output.push_str(&fence);
+                    self.output.push('\n');
+                }
+            },
+
             SyntaxKind::BlankLine => {
-                // Preserve the actual blank line content (multiple newlines/whitespace)
-                self.output.push_str(&node.text().to_string());
+                // A collected link reference definition takes the blank line
+                // after it along
+                let after_collected = node
+                    .prev_sibling()
+                    .is_some_and(|n| self.is_collected_link_reference(&n));
+                if !after_collected {
+                    // Preserve the actual blank line content (multiple newlines/whitespace)
+                    self.output.push_str(&node.text().to_string());
+                }
             }
 
+            SyntaxKind::LinkReferenceDefinition => match self.config.link_reference_definitions {
+                // Written at the end of the document
+                LinkReferenceMode::Collect if self.is_collected_link_reference(node) => {}
+                LinkReferenceMode::Preserve | LinkReferenceMode::Collect => {
+                    self.output.push_str(&link_reference_text(node));
+                    self.output.push('\n');
+                }
+                LinkReferenceMode::Sort => {
+                    // The first definition of a group writes the whole group
+                    let is_first = node
+                        .prev_sibling()
+                        .is_none_or(|n| n.kind() != SyntaxKind::LinkReferenceDefinition);
+                    if is_first {
+                        let mut group: Vec<SyntaxNode> =
+                            std::iter::successors(Some(node.clone()), |n| n.next_sibling())
+                                .take_while(|n| n.kind() == SyntaxKind::LinkReferenceDefinition)
+                                .collect();
+                        group.sort_by_cached_key(link_reference_label);
+                        for definition in group {
+                            self.output.push_str(&link_reference_text(&definition));
+                            self.output.push('\n');
+                        }
+                    }
+                }
+            },
+
             _ => {
                 // Fallback: append node text (should be rare with children_with_tokens above)
                 self.output.push_str(&node.text().to_string());
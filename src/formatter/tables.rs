@@ -0,0 +1,478 @@
+use super::Formatter;
+use crate::config::GridTableMode;
+use crate::syntax::{SyntaxKind, SyntaxNode};
+use textwrap::core::display_width;
+
+/// Alignment of a table column, as given by the colons of its alignment
+/// marker.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Alignment {
+    Default,
+    Left,
+    Right,
+    Center,
+}
+
+impl Alignment {
+    fn from_marker(marker: &str) -> Self {
+        match (marker.starts_with(':'), marker.ends_with(':')) {
+            (true, true) => Alignment::Center,
+            (true, false) => Alignment::Left,
+            (false, true) => Alignment::Right,
+            (false, false) => Alignment::Default,
+        }
+    }
+
+    /// Alignment marker spanning `width` columns, e.g. `:----:`.
+    fn marker(self, width: usize) -> String {
+        let (left, right) = match self {
+            Alignment::Default => ("-", "-"),
+            Alignment::Left => (":", "-"),
+            Alignment::Right => ("-", ":"),
+            Alignment::Center => (":", ":"),
+        };
+        format!("{left}{}{right}", "-".repeat(width.saturating_sub(2)))
+    }
+
+    /// Pad `text` with spaces to `width` display columns.
+    fn pad(self, text: &str, width: usize) -> String {
+        let fill = width.saturating_sub(display_width(text));
+        let (left, right) = match self {
+            Alignment::Default | Alignment::Left => (0, fill),
+            Alignment::Right => (fill, 0),
+            Alignment::Center => (fill / 2, fill - fill / 2),
+        };
+        format!("{}{text}{}", " ".repeat(left), " ".repeat(right))
+    }
+}
+
+impl Formatter {
+    /// Format a pipe table with its columns padded to a common width and
+    /// aligned according to the alignment row.
+    pub(super) fn format_pipe_table(&mut self, node: &SyntaxNode, indent: usize) {
+        let row_cells = |row: &SyntaxNode, kind: SyntaxKind| -> Vec<String> {
+            row.children()
+                .filter(|n| n.kind() == kind)
+                .map(|cell| cell.text().to_string().trim().to_string())
+                .collect()
+        };
+
+        let mut rows = Vec::new();
+        let mut alignments = Vec::new();
+        let mut markers = Vec::new();
+        let mut caption = None;
+        for child in node.children() {
+            match child.kind() {
+                SyntaxKind::TableHeader | SyntaxKind::TableRow => {
+                    let row = if child.kind() == SyntaxKind::TableHeader {
+                        child.children().next()
+                    } else {
+                        Some(child)
+                    };
+                    if let Some(row) = row {
+                        rows.push(row_cells(&row, SyntaxKind::TableCell));
+                    }
+                }
+                SyntaxKind::TableSeparator => {
+                    if let Some(row) = child.children().next() {
+                        markers = row_cells(&row, SyntaxKind::TableAlignment);
+                        alignments = markers
+                            .iter()
+                            .map(|cell| Alignment::from_marker(cell))
+                            .collect();
+                    }
+                }
+                SyntaxKind::TableCaption => caption = Some(child),
+                _ => {}
+            }
+        }
+
+        let columns = rows
+            .iter()
+            .map(Vec::len)
+            .chain(std::iter::once(alignments.len()))
+            .max()
+            .unwrap_or(0);
+        alignments.resize(columns, Alignment::Default);
+
+        // Columns are at least three wide, so the alignment row always has
+        // at least three dashes per column
+        let mut widths = vec![3; columns];
+        for row in &rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(display_width(cell));
+            }
+        }
+
+        // Pandoc takes relative column widths from the dashes of a table
+        // wider than the line width, so those are kept as written
+        let wide = node
+            .children()
+            .filter(|child| child.kind() != SyntaxKind::TableCaption)
+            .flat_map(|child| {
+                let text = child.text().to_string();
+                text.lines().map(display_width).collect::<Vec<_>>()
+            })
+            .any(|width| indent + width > self.config.line_width);
+
+        let prefix = " ".repeat(indent);
+        for (i, row) in rows.iter().enumerate() {
+            self.output.push_str(&prefix);
+            self.output.push('|');
+            for (col, width) in widths.iter().enumerate() {
+                let cell = row.get(col).map_or("", String::as_str);
+                self.output.push(' ');
+                self.output.push_str(&alignments[col].pad(cell, *width));
+                self.output.push_str(" |");
+            }
+            self.output.push('\n');
+
+            if i == 0 {
+                self.output.push_str(&prefix);
+                self.output.push('|');
+                for (col, (alignment, width)) in alignments.iter().zip(&widths).enumerate() {
+                    match markers.get(col) {
+                        Some(marker) if wide => self.output.push_str(marker),
+                        _ => self.output.push_str(&alignment.marker(width + 2)),
+                    }
+                    self.output.push('|');
+                }
+                self.output.push('\n');
+            }
+        }
+
+        if let Some(caption) = caption {
+            self.push_caption(&caption, indent);
+        }
+    }
+}
+
+/// A row line of a grid table, split into cells. Each cell spans the
+/// columns `start..end`.
+struct GridRow<'a> {
+    cells: Vec<(usize, usize, GridCell<'a>)>,
+    /// The character before each cell and after the last one: `|`, or `+`
+    /// where a border meets a cell that spans rows.
+    separators: Vec<char>,
+}
+
+impl GridRow<'_> {
+    /// The columns and display width of each cell with content.
+    fn text_widths(&self) -> impl Iterator<Item = (usize, usize, usize)> + '_ {
+        self.cells.iter().filter_map(|(a, b, cell)| match cell {
+            GridCell::Text(text) => Some((*a, *b, display_width(text))),
+            GridCell::Border(_) => None,
+        })
+    }
+}
+
+/// A cell of a grid table row line.
+enum GridCell<'a> {
+    /// Cell content, without the padding space.
+    Text(&'a str),
+    /// A border segment such as `-----`, in a line next to a cell that
+    /// spans rows.
+    Border(&'a str),
+}
+
+/// Display column of every character in `line`.
+fn char_columns(line: &str) -> Vec<(usize, usize, char)> {
+    let mut col = 0;
+    line.char_indices()
+        .map(|(i, c)| {
+            let start = col;
+            col += display_width(c.encode_utf8(&mut [0; 4]));
+            (i, start, c)
+        })
+        .collect()
+}
+
+/// Split a row line of a grid table into cells, given the display columns
+/// of the column boundaries.
+///
+/// Rows whose pipes line up with the boundaries may have cells spanning
+/// several columns, and the last cell may extend past the right border.
+/// Otherwise the row must have one cell per column, as is the case when a
+/// cell has been edited without adjusting the borders.
+fn split_grid_row<'a>(line: &'a str, boundaries: &[usize]) -> Option<GridRow<'a>> {
+    let line = line.trim_end();
+    let columns = char_columns(line);
+
+    // A `+` on a boundary means a border that only spans some columns,
+    // next to a cell spanning rows
+    if columns
+        .iter()
+        .any(|&(_, col, c)| c == '+' && boundaries.contains(&col))
+    {
+        return split_partial_border(line, &columns, boundaries);
+    }
+
+    let aligned: Vec<(usize, usize)> = columns
+        .iter()
+        .filter(|&&(_, col, c)| c == '|' && boundaries.contains(&col))
+        .map(|&(i, col, _)| (i, boundaries.iter().position(|&b| b == col).unwrap()))
+        .collect();
+    let last = boundaries.len() - 1;
+    let ends_row = |i: usize| i + 1 == line.len() && line.ends_with('|');
+    let pipes = if aligned.first().is_some_and(|&(i, k)| i == 0 && k == 0)
+        && aligned
+            .last()
+            .is_some_and(|&(i, k)| ends_row(i) && k == last)
+    {
+        aligned
+    } else if aligned.first().is_some_and(|&(i, k)| i == 0 && k == 0)
+        && aligned.last().is_some_and(|&(_, k)| k < last)
+        && columns
+            .iter()
+            .filter(|&&(i, _, c)| c == '|' && !ends_row(i))
+            .count()
+            == aligned.len()
+        && ends_row(line.len() - 1)
+    {
+        // Only the last cell has grown past its border
+        let mut pipes = aligned;
+        pipes.push((line.len() - 1, last));
+        pipes
+    } else {
+        let all: Vec<usize> = columns
+            .iter()
+            .filter(|&&(_, _, c)| c == '|')
+            .map(|&(i, _, _)| i)
+            .collect();
+        if all.len() != boundaries.len() || all[0] != 0 || all[last] + 1 != line.len() {
+            return None;
+        }
+        all.into_iter().zip(0..).collect()
+    };
+
+    let cells = pipes
+        .windows(2)
+        .map(|pair| {
+            let (start, a) = pair[0];
+            let (end, b) = pair[1];
+            let text = &line[start + 1..end];
+            let text = text.strip_prefix(' ').unwrap_or(text).trim_end();
+            (a, b, GridCell::Text(text))
+        })
+        .collect();
+    let separators = vec!['|'; pipes.len()];
+    Some(GridRow { cells, separators })
+}
+
+/// Split a line that is partly a border and partly the content of cells
+/// spanning rows, such as `|   +---+`. Its `|` and `+` characters must line
+/// up with the column boundaries.
+fn split_partial_border<'a>(
+    line: &'a str,
+    columns: &[(usize, usize, char)],
+    boundaries: &[usize],
+) -> Option<GridRow<'a>> {
+    let last = boundaries.len() - 1;
+    if columns.last()?.1 != boundaries[last] {
+        return None;
+    }
+    let separators: Vec<(usize, usize, char)> = columns
+        .iter()
+        .filter_map(|&(i, col, c)| {
+            let k = boundaries.iter().position(|&b| b == col)?;
+            matches!(c, '|' | '+').then_some((i, k, c))
+        })
+        .collect();
+    if separators.first()?.1 != 0 || separators.last()?.1 != last {
+        return None;
+    }
+
+    let cells = separators
+        .windows(2)
+        .map(|pair| {
+            let (start, a, _) = pair[0];
+            let (end, b, _) = pair[1];
+            let text = &line[start + 1..end];
+            if !text.is_empty() && text.chars().all(|c| matches!(c, '-' | '=' | ':')) {
+                (a, b, GridCell::Border(text))
+            } else {
+                let text = text.strip_prefix(' ').unwrap_or(text).trim_end();
+                (a, b, GridCell::Text(text))
+            }
+        })
+        .collect();
+    let separators = separators.into_iter().map(|(_, _, c)| c).collect();
+    Some(GridRow { cells, separators })
+}
+
+/// Redraw the border of a grid table so that every column is as wide as
+/// its widest cell. `lines` are the table's lines without indentation,
+/// each flagged as a border or not.
+///
+/// Returns `None` for tables that cannot be redrawn safely, such as tables
+/// with borders of different lengths.
+fn redraw_grid_table(lines: &[(bool, &str)]) -> Option<Vec<String>> {
+    let borders: Vec<&str> = lines
+        .iter()
+        .filter(|(is_border, _)| *is_border)
+        .map(|(_, line)| line.trim_end())
+        .collect();
+    if borders.iter().any(|b| b.len() != borders[0].len()) {
+        return None;
+    }
+
+    // Borders only contain ASCII, so byte offsets are display columns
+    let mut boundaries: Vec<usize> = borders
+        .iter()
+        .flat_map(|b| b.match_indices('+').map(|(i, _)| i))
+        .collect();
+    boundaries.sort_unstable();
+    boundaries.dedup();
+    let column_count = boundaries.len() - 1;
+
+    let mut rows = Vec::new();
+    for (is_border, line) in lines {
+        if !is_border {
+            rows.push(split_grid_row(line, &boundaries)?);
+        }
+    }
+
+    // Size columns to their widest single-column cell, then widen the last
+    // column of spanning cells that still do not fit
+    let mut widths = vec![1; column_count];
+    for row in &rows {
+        for (a, b, width) in row.text_widths() {
+            if b == a + 1 {
+                widths[a] = widths[a].max(width);
+            }
+        }
+    }
+    let span_width =
+        |widths: &[usize], a: usize, b: usize| widths[a..b].iter().sum::<usize>() + 3 * (b - a - 1);
+    let mut spans: Vec<(usize, usize, usize)> = rows
+        .iter()
+        .flat_map(GridRow::text_widths)
+        .filter(|&(a, b, _)| b > a + 1)
+        .collect();
+    spans.sort_by_key(|&(a, b, _)| b - a);
+    for (a, b, needed) in spans {
+        let available = span_width(&widths, a, b);
+        if needed > available {
+            widths[b - 1] += needed - available;
+        }
+    }
+
+    let mut rows = rows.into_iter();
+    let mut out = Vec::new();
+    for (is_border, line) in lines {
+        if *is_border {
+            out.push(redraw_border(line.trim_end(), &boundaries, &widths));
+        } else {
+            let row = rows.next()?;
+            let mut text = String::new();
+            for ((a, b, cell), &separator) in row.cells.into_iter().zip(&row.separators) {
+                let width = span_width(&widths, a, b);
+                text.push(separator);
+                match cell {
+                    GridCell::Text(cell) => {
+                        text.push(' ');
+                        text.push_str(&Alignment::Left.pad(cell, width));
+                        text.push(' ');
+                    }
+                    GridCell::Border(segment) => text.push_str(&redraw_segment(segment, width)),
+                }
+            }
+            text.push(*row.separators.last()?);
+            out.push(text);
+        }
+    }
+    Some(out)
+}
+
+/// Redraw a border line for the given column widths, keeping its fill
+/// character (`-` or `=`), alignment colons and which boundaries have a
+/// `+`.
+fn redraw_border(border: &str, boundaries: &[usize], widths: &[usize]) -> String {
+    let mut out = String::from("+");
+    for (c, width) in widths.iter().enumerate() {
+        let segment = &border[boundaries[c] + 1..boundaries[c + 1]];
+        out.push_str(&redraw_segment(segment, *width));
+
+        let boundary = boundaries[c + 1];
+        if boundary + 1 == border.len() || border.as_bytes()[boundary] == b'+' {
+            out.push('+');
+        } else {
+            out.push(fill_char(segment));
+        }
+    }
+    out
+}
+
+/// The fill character of a border segment, `=` or `-`.
+fn fill_char(segment: &str) -> char {
+    if segment.contains('=') { '=' } else { '-' }
+}
+
+/// Redraw a border segment for content `width` columns wide, keeping its
+/// fill character and alignment colons.
+fn redraw_segment(segment: &str, width: usize) -> String {
+    let fill = fill_char(segment);
+    let left = if segment.starts_with(':') { ':' } else { fill };
+    let right = if segment.ends_with(':') { ':' } else { fill };
+    let mut out = String::from(left);
+    out.extend(std::iter::repeat_n(fill, width));
+    out.push(right);
+    out
+}
+
+impl Formatter {
+    /// Format a grid table, either keeping it as written or redrawing its
+    /// borders, depending on the configuration.
+    pub(super) fn format_grid_table(&mut self, node: &SyntaxNode, indent: usize) {
+        let mut lines = Vec::new();
+        let mut caption = None;
+        for child in node.children() {
+            match child.kind() {
+                SyntaxKind::GridTableBorder | SyntaxKind::GridTableRow => {
+                    let text = child
+                        .children_with_tokens()
+                        .filter_map(|el| el.into_token())
+                        .find(|t| t.kind() == SyntaxKind::TEXT)
+                        .map(|t| t.text().to_string())
+                        .unwrap_or_default();
+                    lines.push((child.kind() == SyntaxKind::GridTableBorder, text));
+                }
+                SyntaxKind::TableCaption => caption = Some(child),
+                _ => {}
+            }
+        }
+
+        let redrawn = match self.config.grid_tables {
+            GridTableMode::Preserve => None,
+            GridTableMode::Redraw => {
+                let lines: Vec<(bool, &str)> =
+                    lines.iter().map(|(b, l)| (*b, l.as_str())).collect();
+                redraw_grid_table(&lines)
+            }
+        };
+        let Some(redrawn) = redrawn else {
+            self.output.push_str(&node.text().to_string());
+            return;
+        };
+
+        let prefix = " ".repeat(indent);
+        for line in redrawn {
+            self.output.push_str(&prefix);
+            self.output.push_str(&line);
+            self.output.push('\n');
+        }
+        if let Some(caption) = caption {
+            self.push_caption(&caption, indent);
+        }
+    }
+
+    /// Write a table caption, separated from the table by a blank line.
+    fn push_caption(&mut self, caption: &SyntaxNode, indent: usize) {
+        self.output.push('\n');
+        for line in caption.text().to_string().lines() {
+            self.output.push_str(&" ".repeat(indent));
+            self.output.push_str(line.trim());
+            self.output.push('\n');
+        }
+    }
+}
@@ -1,4 +1,19 @@
-use crate::syntax::SyntaxNode;
+use crate::syntax::{SyntaxKind, SyntaxNode};
+use rowan::{GreenNodeBuilder, NodeOrToken};
+
+mod citations;
+mod code_spans;
+mod emphasis;
+mod escapes;
+mod html;
+mod latex;
+mod links;
+mod math;
+
+use emphasis::{DelimRun, process_emphasis};
+pub(crate) use latex::latex_command_len;
+use links::{DestPiece, Destination, References};
+pub(crate) use links::{normalize_label, parse_link_label, title_len};
 
 /// The InlineParser takes a block-level CST and processes inline elements within text content.
 /// It traverses the tree, finds TEXT tokens that need inline parsing, and replaces them
@@ -13,11 +28,510 @@ impl InlineParser {
     }
 
     /// Parse inline elements within the block-level CST.
-    /// For now, this is a placeholder that returns the input unchanged.
-    /// TODO: Implement actual inline parsing logic.
+    ///
+    /// The tree is rebuilt, with the text of every node that holds inline
+    /// content (paragraphs, heading text, terms) replaced by inline tokens
+    /// and nodes.
     pub fn parse(self) -> SyntaxNode {
-        // For now, just return the input tree unchanged
-        // This allows the infrastructure to work while we implement inline parsing incrementally
-        self.root
+        // Reference links are only links if their label is defined somewhere
+        let refs: References = self
+            .root
+            .descendants_with_tokens()
+            .filter(|el| el.kind() == SyntaxKind::LinkReferenceLabel)
+            .map(|el| {
+                let label = el.to_string();
+                normalize_label(&label[1..label.len() - 1])
+            })
+            .collect();
+
+        let mut builder = GreenNodeBuilder::new();
+        rebuild_node(&mut builder, &self.root, &refs);
+        SyntaxNode::new_root(builder.finish())
+    }
+}
+
+/// Block nodes whose text is inline content.
+fn is_inline_container(kind: SyntaxKind) -> bool {
+    matches!(
+        kind,
+        SyntaxKind::PARAGRAPH
+            | SyntaxKind::HeadingContent
+            | SyntaxKind::Term
+            | SyntaxKind::TableCell
+    )
+}
+
+fn rebuild_node(builder: &mut GreenNodeBuilder<'static>, node: &SyntaxNode, refs: &References) {
+    builder.start_node(node.kind().into());
+
+    if is_inline_container(node.kind()) {
+        parse_inlines(builder, &node.text().to_string(), refs);
+    } else {
+        for child in node.children_with_tokens() {
+            match child {
+                NodeOrToken::Node(n) => rebuild_node(builder, &n, refs),
+                NodeOrToken::Token(t) => builder.token(t.kind().into(), t.text()),
+            }
+        }
+    }
+
+    builder.finish_node();
+}
+
+/// An inline element recognized while scanning, before emphasis delimiters
+/// are matched up.
+#[derive(Debug)]
+enum Inline<'a> {
+    Text(&'a str),
+    Whitespace(&'a str),
+    Newline,
+    HardLineBreak(&'a str),
+    Escape(&'a str),
+    CodeSpan {
+        marker: &'a str,
+        content: &'a str,
+    },
+    Math {
+        marker: &'a str,
+        content: &'a str,
+    },
+    Delim(DelimRun<'a>),
+    /// `[text](url)`, `![alt](src)`, `[text][ref]`, `[ref][]` or `[ref]`,
+    /// optionally followed by attributes.
+    Link {
+        image: bool,
+        text: Vec<Inline<'a>>,
+        dest: Option<Destination<'a>>,
+        /// Label of a full (`[ref]`) or collapsed (`[]`) reference.
+        reference: Option<&'a str>,
+        attributes: Option<&'a str>,
+    },
+    /// `[content]{attributes}`
+    BracketedSpan {
+        content: Vec<Inline<'a>>,
+        attributes: &'a str,
+    },
+    /// `<https://...>`, including the angle brackets.
+    AutoLink(&'a str),
+    /// `^[note]`
+    Footnote(Vec<Inline<'a>>),
+    /// `[^label]`
+    FootnoteReference(&'a str),
+    /// `[see @doe99, p. 33; -@smith04]`
+    CitationGroup(Vec<citations::CitationItem<'a>>),
+    /// `@doe99` in running text
+    Citation(&'a str),
+    /// The content of an HTML comment, `<!-- ... -->`
+    Comment(&'a str),
+    /// An HTML tag such as `<span class="x">` or `</span>`
+    Html(&'a str),
+    /// A LaTeX command with its arguments, such as `\textbf{bold text}`
+    Latex(&'a str),
+}
+
+/// Parse `text` as inline content and emit the result into `builder`.
+fn parse_inlines(builder: &mut GreenNodeBuilder<'static>, text: &str, refs: &References) {
+    emit(builder, &parse_content(text, refs));
+}
+
+/// Scan `text` into inline elements and match up emphasis delimiters.
+fn parse_content<'a>(text: &'a str, refs: &References) -> Vec<Inline<'a>> {
+    let mut inlines = scan(text, refs);
+    process_emphasis(&mut inlines);
+    inlines
+}
+
+/// Recognize a link, image or bracketed span at the start of `text`.
+///
+/// Reference links (`[text][ref]`, `[ref][]`, `[ref]`) need a matching
+/// definition; otherwise the brackets are literal text.
+fn try_parse_link<'a>(text: &'a str, refs: &References) -> Option<(Inline<'a>, usize)> {
+    let image = text.starts_with("![");
+    let open = usize::from(image);
+    let close = open + links::find_closing_bracket(&text[open..])?;
+    let inner = &text[open + 1..close];
+    let mut pos = close + 1;
+    let after = &text[pos..];
+
+    let mut dest = None;
+    let mut reference = None;
+    if let Some((d, len)) = links::parse_destination(after) {
+        dest = Some(d);
+        pos += len;
+    } else if let Some(len) = links::parse_link_label(after) {
+        let label = &after[1..len - 1];
+        let key = if label.is_empty() { inner } else { label };
+        if !refs.contains(&links::normalize_label(key)) {
+            return None;
+        }
+        reference = Some(label);
+        pos += len;
+    } else if let Some(len) = links::parse_attributes(after).filter(|_| !image) {
+        let span = Inline::BracketedSpan {
+            content: parse_content(inner, refs),
+            attributes: &after[..len],
+        };
+        return Some((span, pos + len));
+    } else if after.starts_with(':') || !refs.contains(&links::normalize_label(inner)) {
+        return None;
     }
+
+    let attributes = links::parse_attributes(&text[pos..]).map(|len| {
+        let attributes = &text[pos..pos + len];
+        pos += len;
+        attributes
+    });
+
+    let link = Inline::Link {
+        image,
+        text: parse_content(inner, refs),
+        dest,
+        reference,
+        attributes,
+    };
+    Some((link, pos))
 }
+
+/// Characters that may start an inline element and therefore end a run of
+/// plain text.
+fn is_special(c: char) -> bool {
+    matches!(
+        c,
+        ' ' | '\t' | '\n' | '\\' | '`' | '*' | '_' | '$' | '<' | '[' | '!' | '^' | '@'
+    )
+}
+
+fn scan<'a>(text: &'a str, refs: &References) -> Vec<Inline<'a>> {
+    let mut inlines = Vec::new();
+    let mut pos = 0;
+
+    while pos < text.len() {
+        let rest = &text[pos..];
+        let c = rest.chars().next().unwrap();
+
+        let len = match c {
+            '\n' => {
+                inlines.push(Inline::Newline);
+                1
+            }
+            ' ' | '\t' => {
+                let len = rest.len() - rest.trim_start_matches([' ', '\t']).len();
+                let ws = &rest[..len];
+                // Two or more spaces at the end of a line, with more text
+                // following, make a hard line break
+                let after = &rest[len..];
+                if ws.len() >= 2
+                    && after.starts_with('\n')
+                    && !after.trim_start().is_empty()
+                    && ws.chars().all(|c| c == ' ')
+                {
+                    inlines.push(Inline::HardLineBreak(ws));
+                } else {
+                    inlines.push(Inline::Whitespace(ws));
+                }
+                len
+            }
+            '\\' => match escapes::try_parse_escape(rest) {
+                Some(escapes::Escape::LineBreak) => {
+                    inlines.push(Inline::HardLineBreak("\\"));
+                    1
+                }
+                Some(escapes::Escape::Char(len)) => {
+                    inlines.push(Inline::Escape(&rest[..len]));
+                    len
+                }
+                None => match latex_command_len(rest) {
+                    Some(len) => {
+                        inlines.push(Inline::Latex(&rest[..len]));
+                        len
+                    }
+                    None => {
+                        inlines.push(Inline::Text("\\"));
+                        1
+                    }
+                },
+            },
+            '`' => match code_spans::try_parse_code_span(rest) {
+                Some(span) => {
+                    inlines.push(Inline::CodeSpan {
+                        marker: &rest[..span.marker_len],
+                        content: &rest[span.marker_len..span.len - span.marker_len],
+                    });
+                    span.len
+                }
+                None => {
+                    // An unmatched backtick run is literal text
+                    let len = rest.chars().take_while(|&c| c == '`').count();
+                    inlines.push(Inline::Text(&rest[..len]));
+                    len
+                }
+            },
+            '*' | '_' => {
+                let len = rest.chars().take_while(|&ch| ch == c).count();
+                let prev = text[..pos].chars().next_back();
+                let next = rest[len..].chars().next();
+                inlines.push(Inline::Delim(DelimRun::new(&rest[..len], prev, next)));
+                len
+            }
+            '[' if links::parse_footnote_reference(rest).is_some() => {
+                let len = links::parse_footnote_reference(rest).unwrap();
+                inlines.push(Inline::FootnoteReference(&rest[..len]));
+                len
+            }
+            '[' if citations::parse_citation_group(rest).is_some() => {
+                let (items, len) = citations::parse_citation_group(rest).unwrap();
+                inlines.push(Inline::CitationGroup(items));
+                len
+            }
+            // In-text citations must not follow a word character, as in an
+            // e-mail address
+            '@' if !text[..pos].ends_with(|c: char| c.is_alphanumeric())
+                && citations::citation_key_len(rest).is_some() =>
+            {
+                let len = citations::citation_key_len(rest).unwrap();
+                inlines.push(Inline::Citation(&rest[..len]));
+                len
+            }
+            '^' if rest.starts_with("^[") && links::find_closing_bracket(&rest[1..]).is_some() => {
+                let close = 1 + links::find_closing_bracket(&rest[1..]).unwrap();
+                inlines.push(Inline::Footnote(parse_content(&rest[2..close], refs)));
+                close + 1
+            }
+            '[' | '!' if rest.starts_with('[') || rest.starts_with("![") => {
+                match try_parse_link(rest, refs) {
+                    Some((link, len)) => {
+                        inlines.push(link);
+                        len
+                    }
+                    None => {
+                        let len = if c == '!' { 2 } else { 1 };
+                        inlines.push(Inline::Text(&rest[..len]));
+                        len
+                    }
+                }
+            }
+            '<' if links::parse_autolink(rest).is_some() => {
+                let len = links::parse_autolink(rest).unwrap();
+                inlines.push(Inline::AutoLink(&rest[..len]));
+                len
+            }
+            '$' => match math::try_parse_inline_math(rest) {
+                Some(math) => {
+                    inlines.push(Inline::Math {
+                        marker: &rest[..math.marker_len],
+                        content: &rest[math.marker_len..math.len - math.marker_len],
+                    });
+                    math.len
+                }
+                None => {
+                    // A `$` that does not start math is literal text
+                    let len = rest.chars().take_while(|&c| c == '$').count();
+                    inlines.push(Inline::Text(&rest[..len]));
+                    len
+                }
+            },
+            '<' if html::parse_html_comment(rest).is_some() => {
+                let len = html::parse_html_comment(rest).unwrap();
+                inlines.push(Inline::Comment(&rest[4..len - 3]));
+                len
+            }
+            '<' if html::parse_html_tag(rest).is_some() => {
+                let len = html::parse_html_tag(rest).unwrap();
+                inlines.push(Inline::Html(&rest[..len]));
+                len
+            }
+            _ => {
+                let len = rest
+                    .char_indices()
+                    .skip(1)
+                    .find(|&(_, c)| is_special(c))
+                    .map_or(rest.len(), |(i, _)| i);
+                inlines.push(Inline::Text(&rest[..len]));
+                len
+            }
+        };
+
+        pos += len;
+    }
+
+    inlines
+}
+
+fn emit(builder: &mut GreenNodeBuilder<'static>, inlines: &[Inline]) {
+    for inline in inlines {
+        match inline {
+            Inline::Text(t) => builder.token(SyntaxKind::TEXT.into(), t),
+            Inline::Whitespace(t) => builder.token(SyntaxKind::WHITESPACE.into(), t),
+            Inline::Newline => builder.token(SyntaxKind::NEWLINE.into(), "\n"),
+            Inline::HardLineBreak(t) => builder.token(SyntaxKind::HardLineBreak.into(), t),
+            Inline::Escape(t) => builder.token(SyntaxKind::Escape.into(), t),
+            Inline::CodeSpan { marker, content } => {
+                builder.start_node(SyntaxKind::CodeSpan.into());
+                builder.token(SyntaxKind::CodeSpanMarker.into(), marker);
+                if !content.is_empty() {
+                    builder.token(SyntaxKind::TEXT.into(), content);
+                }
+                builder.token(SyntaxKind::CodeSpanMarker.into(), marker);
+                builder.finish_node();
+            }
+            Inline::Math { marker, content } => {
+                builder.start_node(SyntaxKind::InlineMath.into());
+                builder.token(SyntaxKind::InlineMathMarker.into(), marker);
+                builder.token(SyntaxKind::TEXT.into(), content);
+                builder.token(SyntaxKind::InlineMathMarker.into(), marker);
+                builder.finish_node();
+            }
+            Inline::Delim(run) => run.emit(builder),
+            Inline::Link {
+                image,
+                text,
+                dest,
+                reference,
+                attributes,
+            } => {
+                let (kind, start, marker) = if *image {
+                    (SyntaxKind::ImageLink, SyntaxKind::ImageLinkStart, "![")
+                } else {
+                    (SyntaxKind::Link, SyntaxKind::LinkStart, "[")
+                };
+                builder.start_node(kind.into());
+                builder.token(start.into(), marker);
+                builder.start_node(SyntaxKind::LinkText.into());
+                emit(builder, text);
+                builder.finish_node();
+                builder.token(SyntaxKind::LinkTextEnd.into(), "]");
+                if let Some(dest) = dest {
+                    emit_destination(builder, dest);
+                }
+                if let Some(label) = reference {
+                    builder.start_node(SyntaxKind::LinkRef.into());
+                    builder.token(SyntaxKind::TEXT.into(), "[");
+                    if !label.is_empty() {
+                        builder.token(SyntaxKind::TEXT.into(), label);
+                    }
+                    builder.token(SyntaxKind::TEXT.into(), "]");
+                    builder.finish_node();
+                }
+                if let Some(attributes) = attributes {
+                    emit_attributes(builder, attributes);
+                }
+                builder.finish_node();
+            }
+            Inline::BracketedSpan {
+                content,
+                attributes,
+            } => {
+                builder.start_node(SyntaxKind::BracketedSpan.into());
+                builder.token(SyntaxKind::TEXT.into(), "[");
+                builder.start_node(SyntaxKind::SpanContent.into());
+                emit(builder, content);
+                builder.finish_node();
+                builder.token(SyntaxKind::TEXT.into(), "]");
+                emit_attributes(builder, attributes);
+                builder.finish_node();
+            }
+            Inline::Comment(content) => {
+                builder.start_node(SyntaxKind::Comment.into());
+                builder.token(SyntaxKind::CommentStart.into(), "<!--");
+                if !content.is_empty() {
+                    builder.token(SyntaxKind::TEXT.into(), content);
+                }
+                builder.token(SyntaxKind::CommentEnd.into(), "-->");
+                builder.finish_node();
+            }
+            Inline::Latex(command) => {
+                builder.start_node(SyntaxKind::LatexCommand.into());
+                builder.token(SyntaxKind::TEXT.into(), command);
+                builder.finish_node();
+            }
+            Inline::Html(tag) => {
+                builder.start_node(SyntaxKind::InlineHtml.into());
+                builder.token(SyntaxKind::TEXT.into(), tag);
+                builder.finish_node();
+            }
+            Inline::Footnote(content) => {
+                builder.start_node(SyntaxKind::InlineFootnote.into());
+                builder.token(SyntaxKind::InlineFootnoteStart.into(), "^[");
+                emit(builder, content);
+                builder.token(SyntaxKind::InlineFootnoteEnd.into(), "]");
+                builder.finish_node();
+            }
+            Inline::FootnoteReference(t) => {
+                builder.token(SyntaxKind::FootnoteReference.into(), t);
+            }
+            Inline::CitationGroup(items) => {
+                builder.start_node(SyntaxKind::Citation.into());
+                builder.token(SyntaxKind::TEXT.into(), "[");
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        builder.token(SyntaxKind::CitationSeparator.into(), ";");
+                    }
+                    emit_whitespace(builder, item.leading);
+                    builder.start_node(SyntaxKind::CitationItem.into());
+                    if !item.prefix.is_empty() {
+                        builder.token(SyntaxKind::TEXT.into(), item.prefix);
+                    }
+                    builder.token(SyntaxKind::CitationMarker.into(), item.marker);
+                    builder.token(SyntaxKind::CitationKey.into(), item.key);
+                    if !item.suffix.is_empty() {
+                        builder.token(SyntaxKind::TEXT.into(), item.suffix);
+                    }
+                    builder.finish_node();
+                    emit_whitespace(builder, item.trailing);
+                }
+                builder.token(SyntaxKind::TEXT.into(), "]");
+                builder.finish_node();
+            }
+            Inline::Citation(t) => {
+                builder.start_node(SyntaxKind::Citation.into());
+                builder.start_node(SyntaxKind::CitationItem.into());
+                builder.token(SyntaxKind::CitationMarker.into(), "@");
+                builder.token(SyntaxKind::CitationKey.into(), &t[1..]);
+                builder.finish_node();
+                builder.finish_node();
+            }
+            Inline::AutoLink(t) => {
+                builder.start_node(SyntaxKind::AutoLink.into());
+                builder.token(SyntaxKind::TEXT.into(), "<");
+                builder.token(SyntaxKind::LinkUrl.into(), &t[1..t.len() - 1]);
+                builder.token(SyntaxKind::TEXT.into(), ">");
+                builder.finish_node();
+            }
+        }
+    }
+}
+
+fn emit_destination(builder: &mut GreenNodeBuilder<'static>, dest: &Destination) {
+    builder.start_node(SyntaxKind::LinkDest.into());
+    for piece in &dest.pieces {
+        match piece {
+            DestPiece::Paren(t) => builder.token(SyntaxKind::TEXT.into(), t),
+            DestPiece::Url(t) => builder.token(SyntaxKind::LinkUrl.into(), t),
+            DestPiece::Title(t) => builder.token(SyntaxKind::LinkTitle.into(), t),
+            DestPiece::Whitespace(t) => builder.token(SyntaxKind::WHITESPACE.into(), t),
+            DestPiece::Newline => builder.token(SyntaxKind::NEWLINE.into(), "\n"),
+        }
+    }
+    builder.finish_node();
+}
+
+/// Emit whitespace that may span lines as WHITESPACE and NEWLINE tokens.
+fn emit_whitespace(builder: &mut GreenNodeBuilder<'static>, ws: &str) {
+    for (i, part) in ws.split('\n').enumerate() {
+        if i > 0 {
+            builder.token(SyntaxKind::NEWLINE.into(), "\n");
+        }
+        if !part.is_empty() {
+            builder.token(SyntaxKind::WHITESPACE.into(), part);
+        }
+    }
+}
+
+fn emit_attributes(builder: &mut GreenNodeBuilder<'static>, attributes: &str) {
+    builder.start_node(SyntaxKind::Attribute.into());
+    builder.token(SyntaxKind::TEXT.into(), attributes);
+    builder.finish_node();
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,115 @@
+/// A single citation within a citation group: `see -@doe99, p. 33`.
+#[derive(Debug)]
+pub(super) struct CitationItem<'a> {
+    /// Whitespace before the item.
+    pub leading: &'a str,
+    pub prefix: &'a str,
+    /// `@`, or `-@` to suppress the author.
+    pub marker: &'a str,
+    /// The key, e.g. `doe99` or `{weird key}`.
+    pub key: &'a str,
+    /// Locator and suffix, e.g. `, p. 33`.
+    pub suffix: &'a str,
+    /// Whitespace after the item.
+    pub trailing: &'a str,
+}
+
+/// Characters allowed inside a citation key when followed by an
+/// alphanumeric character.
+fn is_internal_punctuation(c: char) -> bool {
+    ":.#$%&-+?<>~/".contains(c)
+}
+
+fn is_key_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Length of a citation key, `@key` or `@{weird key}`, at the start of
+/// `text`, including the `@`.
+///
+/// Keys start with a letter, digit or `_` and may contain internal
+/// punctuation; trailing punctuation is not part of the key.
+pub(super) fn citation_key_len(text: &str) -> Option<usize> {
+    let key = text.strip_prefix('@')?;
+    if let Some(braced) = key.strip_prefix('{') {
+        let end = braced.find(['}', '\n'])?;
+        return (end > 0 && braced[end..].starts_with('}')).then_some(end + 3);
+    }
+
+    let mut chars = key.char_indices().peekable();
+    let mut len = 0;
+    while let Some((i, c)) = chars.next() {
+        let next = chars.peek().map(|&(_, n)| n);
+        if is_key_char(c) || (i > 0 && is_internal_punctuation(c) && next.is_some_and(is_key_char))
+        {
+            len = i + c.len_utf8();
+        } else {
+            break;
+        }
+    }
+    (len > 0).then_some(len + 1)
+}
+
+/// Parse a bracketed citation group, `[see @doe99, p. 33; -@smith04]`, at
+/// the start of `text`. Every `;`-separated item must contain a key.
+/// Returns the items and the total length.
+pub(super) fn parse_citation_group(text: &str) -> Option<(Vec<CitationItem<'_>>, usize)> {
+    let inner = text.strip_prefix('[')?;
+
+    // Find the closing bracket and the item separators at the top level
+    let mut depth = 0usize;
+    let mut bounds = vec![0];
+    let mut close = None;
+    for (i, c) in inner.char_indices() {
+        match c {
+            '[' | '{' => depth += 1,
+            ']' if depth == 0 => {
+                close = Some(i);
+                break;
+            }
+            ']' | '}' => depth = depth.saturating_sub(1),
+            ';' if depth == 0 => bounds.push(i + 1),
+            _ => {}
+        }
+    }
+    let close = close?;
+
+    let mut items = Vec::new();
+    for (n, &start) in bounds.iter().enumerate() {
+        let end = bounds.get(n + 1).map_or(close, |&next| next - 1);
+        items.push(parse_item(&inner[start..end])?);
+    }
+
+    Some((items, close + 2))
+}
+
+/// Split one item of a citation group into its parts.
+fn parse_item(item: &str) -> Option<CitationItem<'_>> {
+    let body = item.trim();
+    let leading = &item[..item.len() - item.trim_start().len()];
+    let trailing = &item[leading.len() + body.len()..];
+
+    // The key is the first `@` at the start of the item or after whitespace
+    let at = body.char_indices().find_map(|(i, c)| {
+        let before = body[..i].chars().next_back();
+        let marker_start = if before == Some('-') { i - 1 } else { i };
+        let boundary = body[..marker_start]
+            .chars()
+            .next_back()
+            .is_none_or(char::is_whitespace);
+        (c == '@' && boundary && citation_key_len(&body[i..]).is_some()).then_some(marker_start)
+    })?;
+
+    let marker_len = if body[at..].starts_with('-') { 2 } else { 1 };
+    let key_len = citation_key_len(&body[at + marker_len - 1..])? - 1;
+    let key_start = at + marker_len;
+
+    Some(CitationItem {
+        leading,
+        prefix: &body[..at],
+        marker: &body[at..key_start],
+        key: &body[key_start..key_start + key_len],
+        suffix: &body[key_start + key_len..],
+        trailing,
+    })
+}
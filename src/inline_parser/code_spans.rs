@@ -0,0 +1,33 @@
+pub(super) struct CodeSpanMatch {
+    /// Length of the backtick run on each side.
+    pub marker_len: usize,
+    /// Total length of the code span, including both markers.
+    pub len: usize,
+}
+
+/// Recognize a code span at the start of `text`.
+///
+/// A code span opens with a run of backticks and closes with the next run
+/// of exactly the same length. The content is taken verbatim.
+pub(super) fn try_parse_code_span(text: &str) -> Option<CodeSpanMatch> {
+    let marker_len = text.chars().take_while(|&c| c == '`').count();
+    if marker_len == 0 {
+        return None;
+    }
+
+    let mut pos = marker_len;
+    while pos < text.len() {
+        let rest = &text[pos..];
+        let start = rest.find('`')?;
+        let run = rest[start..].chars().take_while(|&c| c == '`').count();
+        if run == marker_len {
+            return Some(CodeSpanMatch {
+                marker_len,
+                len: pos + start + run,
+            });
+        }
+        pos += start + run;
+    }
+
+    None
+}
@@ -0,0 +1,252 @@
+use super::Inline;
+use crate::syntax::SyntaxKind;
+use rowan::GreenNodeBuilder;
+
+/// A run of `*` or `_` characters that may open or close emphasis.
+#[derive(Debug)]
+pub(super) struct DelimRun<'a> {
+    text: &'a str,
+    /// The characters just before and after the run.
+    prev: Option<char>,
+    next: Option<char>,
+    /// Delimiters used to close emphasis (1 or 2 each), innermost first.
+    closes: Vec<usize>,
+    /// Delimiters used to open emphasis (1 or 2 each), innermost first.
+    opens: Vec<usize>,
+}
+
+impl<'a> DelimRun<'a> {
+    pub(super) fn new(text: &'a str, prev: Option<char>, next: Option<char>) -> Self {
+        Self {
+            text,
+            prev,
+            next,
+            closes: Vec::new(),
+            opens: Vec::new(),
+        }
+    }
+
+    fn delim_char(&self) -> char {
+        self.text.as_bytes()[0] as char
+    }
+
+    /// Number of delimiters not used for emphasis.
+    fn remaining(&self) -> usize {
+        self.text.len() - self.closes.iter().sum::<usize>() - self.opens.iter().sum::<usize>()
+    }
+
+    /// Emit the run: delimiters closing emphasis come first (they are taken
+    /// from the left), then unused delimiters as text, then delimiters
+    /// opening emphasis (taken from the right).
+    pub(super) fn emit(&self, builder: &mut GreenNodeBuilder<'static>) {
+        let mut offset = 0;
+
+        for &n in &self.closes {
+            builder.token(marker_kind(n).into(), &self.text[offset..offset + n]);
+            builder.finish_node();
+            offset += n;
+        }
+
+        let remaining = self.remaining();
+        if remaining > 0 {
+            builder.token(
+                SyntaxKind::TEXT.into(),
+                &self.text[offset..offset + remaining],
+            );
+            offset += remaining;
+        }
+
+        for &n in self.opens.iter().rev() {
+            builder.start_node(node_kind(n).into());
+            builder.token(marker_kind(n).into(), &self.text[offset..offset + n]);
+            offset += n;
+        }
+    }
+}
+
+fn node_kind(n: usize) -> SyntaxKind {
+    if n == 2 {
+        SyntaxKind::Strong
+    } else {
+        SyntaxKind::Emphasis
+    }
+}
+
+fn marker_kind(n: usize) -> SyntaxKind {
+    if n == 2 {
+        SyntaxKind::StrongMarker
+    } else {
+        SyntaxKind::EmphasisMarker
+    }
+}
+
+/// Match emphasis openers and closers, recording the matches on the
+/// delimiter runs.
+///
+/// This follows Pandoc's recursive-descent parser rather than CommonMark's
+/// delimiter stack: an opener is matched with the first following run that
+/// can close it, so `**foo*bar**` is not strong emphasis. A run opens only
+/// if it is not followed by whitespace, and `_` cannot open right after an
+/// alphanumeric character or a closing delimiter. Unmatched openers are
+/// literal text but keep any emphasis nested inside them.
+pub(super) fn process_emphasis(inlines: &mut [Inline]) {
+    let mut parser = EmphasisParser {
+        inlines,
+        pos: 0,
+        offset: 0,
+        after_closer: None,
+    };
+    while parser.parse_inline() {}
+}
+
+struct EmphasisParser<'i, 'a> {
+    inlines: &'i mut [Inline<'a>],
+    /// The current inline, and the number of delimiters of the current run
+    /// already consumed.
+    pos: usize,
+    offset: usize,
+    /// The position right after the last closing delimiter.
+    after_closer: Option<(usize, usize)>,
+}
+
+impl<'a> EmphasisParser<'_, 'a> {
+    fn run(&mut self, idx: usize) -> &mut DelimRun<'a> {
+        match &mut self.inlines[idx] {
+            Inline::Delim(run) => run,
+            _ => unreachable!("index does not point at a delimiter run"),
+        }
+    }
+
+    /// The number of unconsumed `c` delimiters at the current position.
+    fn run_at(&self, c: char) -> usize {
+        match self.inlines.get(self.pos) {
+            Some(Inline::Delim(run)) if run.delim_char() == c => run.text.len() - self.offset,
+            _ => 0,
+        }
+    }
+
+    fn advance(&mut self, n: usize) {
+        self.offset += n;
+        if let Some(Inline::Delim(run)) = self.inlines.get(self.pos)
+            && self.offset == run.text.len()
+        {
+            self.pos += 1;
+            self.offset = 0;
+        }
+    }
+
+    /// Whether `n` delimiters at the current position can close emphasis.
+    /// A closing `_` must not be followed by an alphanumeric character.
+    fn is_ender(&self, c: char, n: usize) -> bool {
+        let available = self.run_at(c);
+        if available < n {
+            return false;
+        }
+        if c == '*' || n < available {
+            return true;
+        }
+        match &self.inlines[self.pos] {
+            Inline::Delim(run) => !run.next.is_some_and(char::is_alphanumeric),
+            _ => false,
+        }
+    }
+
+    fn take_ender(&mut self, c: char, n: usize) -> bool {
+        if !self.is_ender(c, n) {
+            return false;
+        }
+        let pos = self.pos;
+        // Three delimiters close emphasis inside strong emphasis
+        let closes: &[usize] = if n == 3 { &[1, 2] } else { &[n] };
+        self.run(pos).closes.extend_from_slice(closes);
+        self.advance(n);
+        self.after_closer = Some((self.pos, self.offset));
+        true
+    }
+
+    /// Step over one inline, parsing any emphasis it opens. Returns false
+    /// at the end of the input.
+    fn parse_inline(&mut self) -> bool {
+        match self.inlines.get(self.pos) {
+            None => false,
+            Some(Inline::Delim(run)) => {
+                let c = run.delim_char();
+                self.parse_enclosure(c);
+                true
+            }
+            Some(_) => {
+                self.pos += 1;
+                true
+            }
+        }
+    }
+
+    fn parse_enclosure(&mut self, c: char) {
+        let idx = self.pos;
+        let run = self.run(idx);
+        let after_word = run.prev.is_some_and(char::is_alphanumeric);
+        let followed_by_space = run.next.is_some_and(char::is_whitespace);
+        let len = self.run_at(c);
+
+        let literal = followed_by_space
+            || (c == '_'
+                && (self.offset > 0
+                    || after_word
+                    || self.after_closer == Some((self.pos, self.offset))));
+        self.advance(len);
+        if literal {
+            return;
+        }
+        match len {
+            3 => self.parse_three(c, idx),
+            2 => self.parse_two(c, idx),
+            1 => self.parse_one(c, idx),
+            _ => {}
+        }
+    }
+
+    /// Parse the content of `***`, deciding from the closing delimiters
+    /// which of strong and emphasis is the outer one.
+    fn parse_three(&mut self, c: char, opener: usize) {
+        while !self.is_ender(c, 1) && self.parse_inline() {}
+
+        if self.take_ender(c, 3) {
+            self.run(opener).opens.extend_from_slice(&[1, 2]);
+        } else if self.take_ender(c, 2) {
+            self.run(opener).opens.push(2);
+            self.parse_one(c, opener);
+        } else if self.take_ender(c, 1) {
+            self.run(opener).opens.push(1);
+            self.parse_two(c, opener);
+        }
+    }
+
+    fn parse_two(&mut self, c: char, opener: usize) {
+        while !self.is_ender(c, 2) && self.parse_inline() {}
+
+        if self.take_ender(c, 2) {
+            self.run(opener).opens.push(2);
+        }
+    }
+
+    fn parse_one(&mut self, c: char, opener: usize) {
+        loop {
+            if self.is_ender(c, 1) {
+                // A pair of delimiters opens strong emphasis inside the
+                // emphasis rather than closing it
+                if self.run_at(c) != 2 {
+                    break;
+                }
+                let idx = self.pos;
+                self.advance(2);
+                self.parse_two(c, idx);
+            } else if !self.parse_inline() {
+                break;
+            }
+        }
+
+        if self.take_ender(c, 1) {
+            self.run(opener).opens.push(1);
+        }
+    }
+}
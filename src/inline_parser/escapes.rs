@@ -0,0 +1,22 @@
+/// A backslash escape.
+pub(super) enum Escape {
+    /// `\` followed by an escapable character; holds the total length.
+    Char(usize),
+    /// `\` at the end of a line, which is a hard line break.
+    LineBreak,
+}
+
+/// Recognize a backslash escape at the start of `text`.
+///
+/// Following Pandoc, any ASCII punctuation character and the space (a
+/// nonbreaking space) can be escaped. A backslash before a newline is a hard
+/// line break.
+pub(super) fn try_parse_escape(text: &str) -> Option<Escape> {
+    let next = text.strip_prefix('\\')?.chars().next()?;
+    match next {
+        '\n' => Some(Escape::LineBreak),
+        ' ' => Some(Escape::Char(2)),
+        c if c.is_ascii_punctuation() => Some(Escape::Char(2)),
+        _ => None,
+    }
+}
@@ -0,0 +1,70 @@
+/// Length of an HTML comment, `<!-- ... -->`, at the start of `text`.
+pub(super) fn parse_html_comment(text: &str) -> Option<usize> {
+    let inner = text.strip_prefix("<!--")?;
+    inner.find("-->").map(|end| end + 7)
+}
+
+fn is_tag_name_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '-'
+}
+
+/// Length of an attribute value, quoted or not, at the start of `text`.
+fn attribute_value_len(text: &str) -> Option<usize> {
+    let quote = text.chars().next()?;
+    if quote == '"' || quote == '\'' {
+        return text[1..].find(quote).map(|end| end + 2);
+    }
+    let len = text
+        .find(|c: char| c.is_whitespace() || "\"'=<>`".contains(c))
+        .unwrap_or(text.len());
+    (len > 0).then_some(len)
+}
+
+/// Length of an HTML tag at the start of `text`: an opening tag with
+/// attributes, such as `<span class="x">` or `<br/>`, or a closing tag
+/// such as `</span>`. Tags may span lines.
+pub(super) fn parse_html_tag(text: &str) -> Option<usize> {
+    let rest = text.strip_prefix('<')?;
+    let closing = rest.starts_with('/');
+    let name = if closing { &rest[1..] } else { rest };
+    if !name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    let name_len = name.find(|c| !is_tag_name_char(c)).unwrap_or(name.len());
+    let mut pos = text.len() - name.len() + name_len;
+
+    if closing {
+        pos += text[pos..].len() - text[pos..].trim_start().len();
+        return text[pos..].starts_with('>').then_some(pos + 1);
+    }
+
+    loop {
+        let ws = text[pos..].len() - text[pos..].trim_start().len();
+        let after = &text[pos + ws..];
+        if after.starts_with('>') {
+            return Some(pos + ws + 1);
+        }
+        if after.starts_with("/>") {
+            return Some(pos + ws + 2);
+        }
+
+        // Attributes must be separated by whitespace
+        if ws == 0 || !after.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_' || c == ':')
+        {
+            return None;
+        }
+        pos += ws;
+        pos += text[pos..]
+            .find(|c: char| !(c.is_ascii_alphanumeric() || "_.:-".contains(c)))
+            .unwrap_or(text.len() - pos);
+
+        // Optional value
+        let rest = &text[pos..];
+        let before_eq = rest.len() - rest.trim_start().len();
+        if rest[before_eq..].starts_with('=') {
+            let value = &rest[before_eq + 1..];
+            let after_eq = value.len() - value.trim_start().len();
+            pos += before_eq + 1 + after_eq + attribute_value_len(&value[after_eq..])?;
+        }
+    }
+}
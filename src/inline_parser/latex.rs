@@ -0,0 +1,57 @@
+/// Length of a balanced group opened by `open` at the start of `text`, such
+/// as `{...}` or `[...]`. Escaped delimiters do not count, and groups cannot
+/// span a blank line.
+fn group_len(text: &str, open: char, close: char) -> Option<usize> {
+    let mut depth = 0;
+    let mut escaped = false;
+    for (i, c) in text.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '\n' if text[i + 1..]
+                .trim_start_matches([' ', '\t'])
+                .starts_with('\n') =>
+            {
+                return None;
+            }
+            c if c == open => depth += 1,
+            c if c == close => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i + 1);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Length of a LaTeX command at the start of `text`: a backslash, a name
+/// made of letters (optionally starred) and any number of `[...]` and
+/// `{...}` arguments directly following it, e.g.
+/// `\includegraphics[width=0.5\textwidth]{figure.png}`.
+pub(crate) fn latex_command_len(text: &str) -> Option<usize> {
+    let name = text.strip_prefix('\\')?;
+    let name_len = name
+        .find(|c: char| !c.is_ascii_alphabetic())
+        .unwrap_or(name.len());
+    if name_len == 0 {
+        return None;
+    }
+    let mut pos = 1 + name_len;
+    if text[pos..].starts_with('*') {
+        pos += 1;
+    }
+    loop {
+        let rest = &text[pos..];
+        let len = if rest.starts_with('{') {
+            group_len(rest, '{', '}')?
+        } else if rest.starts_with('[') {
+            group_len(rest, '[', ']')?
+        } else {
+            return Some(pos);
+        };
+        pos += len;
+    }
+}
@@ -0,0 +1,249 @@
+use super::code_spans::try_parse_code_span;
+use super::escapes::{Escape, try_parse_escape};
+use std::collections::HashSet;
+
+/// Labels of the link reference definitions in a document, normalized with
+/// [`normalize_label`].
+pub(super) type References = HashSet<String>;
+
+/// The inline destination of a link, `(url "title")`, split into parts.
+/// Whitespace between the parts is kept in `pieces` as-is.
+#[derive(Debug)]
+pub(super) struct Destination<'a> {
+    pub pieces: Vec<DestPiece<'a>>,
+}
+
+#[derive(Debug)]
+pub(super) enum DestPiece<'a> {
+    /// `(` or `)`
+    Paren(&'a str),
+    Url(&'a str),
+    Title(&'a str),
+    Whitespace(&'a str),
+    Newline,
+}
+
+/// Normalize a reference label for matching: case-insensitive, with runs
+/// of whitespace collapsed.
+pub(crate) fn normalize_label(label: &str) -> String {
+    label
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// Byte offset of the `]` closing the `[` at the start of `text`.
+///
+/// Escaped brackets and brackets inside code spans do not count.
+pub(super) fn find_closing_bracket(text: &str) -> Option<usize> {
+    debug_assert!(text.starts_with('['));
+    let mut depth = 0;
+    let mut pos = 0;
+    while pos < text.len() {
+        let rest = &text[pos..];
+        let c = rest.chars().next().unwrap();
+        match c {
+            '\\' => {
+                if let Some(Escape::Char(len)) = try_parse_escape(rest) {
+                    pos += len;
+                    continue;
+                }
+            }
+            '`' => {
+                if let Some(span) = try_parse_code_span(rest) {
+                    pos += span.len;
+                    continue;
+                }
+                pos += rest.chars().take_while(|&c| c == '`').count();
+                continue;
+            }
+            '[' => depth += 1,
+            ']' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(pos);
+                }
+            }
+            _ => {}
+        }
+        pos += c.len_utf8();
+    }
+    None
+}
+
+/// Length of a link label, `[label]`, at the start of `text`. Labels cannot
+/// contain unescaped brackets.
+pub(crate) fn parse_link_label(text: &str) -> Option<usize> {
+    let inner = text.strip_prefix('[')?;
+    let mut escaped = false;
+    for (i, c) in inner.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '[' => return None,
+            ']' => return Some(i + 2),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Length of a footnote reference, `[^label]`, at the start of `text`.
+/// Labels cannot contain whitespace or brackets.
+pub(super) fn parse_footnote_reference(text: &str) -> Option<usize> {
+    let label = text.strip_prefix("[^")?;
+    let end = label.find(|c: char| c.is_whitespace() || c == '[' || c == ']')?;
+    (end > 0 && label[end..].starts_with(']')).then_some(end + 3)
+}
+
+/// Parse an inline link destination, `(url "title")`, at the start of
+/// `text`. Returns the destination and its total length.
+pub(super) fn parse_destination(text: &str) -> Option<(Destination<'_>, usize)> {
+    if !text.starts_with('(') {
+        return None;
+    }
+    let mut pieces = vec![DestPiece::Paren("(")];
+    let mut pos = 1;
+
+    pos += take_whitespace(&text[pos..], &mut pieces);
+
+    let url_len = url_len(&text[pos..])?;
+    if url_len > 0 {
+        pieces.push(DestPiece::Url(&text[pos..pos + url_len]));
+        pos += url_len;
+    }
+
+    let ws = take_whitespace(&text[pos..], &mut pieces);
+    pos += ws;
+    if ws > 0
+        && url_len > 0
+        && let Some(title_len) = title_len(&text[pos..])
+    {
+        pieces.push(DestPiece::Title(&text[pos..pos + title_len]));
+        pos += title_len;
+        pos += take_whitespace(&text[pos..], &mut pieces);
+    }
+
+    if !text[pos..].starts_with(')') {
+        return None;
+    }
+    pieces.push(DestPiece::Paren(")"));
+    Some((Destination { pieces }, pos + 1))
+}
+
+/// Consume spaces, tabs and at most one newline at the start of `text`,
+/// recording them as destination pieces.
+fn take_whitespace<'a>(text: &'a str, pieces: &mut Vec<DestPiece<'a>>) -> usize {
+    let mut pos = 0;
+    let mut seen_newline = false;
+    loop {
+        let rest = &text[pos..];
+        let ws = rest.len() - rest.trim_start_matches([' ', '\t']).len();
+        if ws > 0 {
+            pieces.push(DestPiece::Whitespace(&rest[..ws]));
+            pos += ws;
+        } else if rest.starts_with('\n') && !seen_newline {
+            pieces.push(DestPiece::Newline);
+            seen_newline = true;
+            pos += 1;
+        } else {
+            return pos;
+        }
+    }
+}
+
+/// Length of the URL at the start of `text`: either `<...>` or a run of
+/// non-whitespace characters with balanced parentheses.
+fn url_len(text: &str) -> Option<usize> {
+    if let Some(inner) = text.strip_prefix('<') {
+        let end = inner.find(['>', '<', '\n'])?;
+        return inner[end..].starts_with('>').then_some(end + 2);
+    }
+
+    let mut depth = 0usize;
+    let mut escaped = false;
+    for (i, c) in text.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '(' => depth += 1,
+            ')' if depth == 0 => return Some(i),
+            ')' => depth -= 1,
+            c if c.is_whitespace() => return (depth == 0).then_some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Length of a link title, `"..."`, `'...'` or `(...)`, at the start of
+/// `text`.
+pub(crate) fn title_len(text: &str) -> Option<usize> {
+    let open = text.chars().next()?;
+    let close = match open {
+        '"' | '\'' => open,
+        '(' => ')',
+        _ => return None,
+    };
+    let mut escaped = false;
+    for (i, c) in text.char_indices().skip(1) {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            c if c == close => return Some(i + 1),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Length of an attribute block, `{...}`, at the start of `text`. Braces
+/// inside quoted values do not end the block.
+pub(super) fn parse_attributes(text: &str) -> Option<usize> {
+    let inner = text.strip_prefix('{')?;
+    let mut quote = None;
+    for (i, c) in inner.char_indices() {
+        match (quote, c) {
+            (Some(q), _) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '}') => return Some(i + 2),
+            (None, '{' | '\n') => return None,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Length of an autolink, `<scheme:...>` or `<user@example.com>`, at the
+/// start of `text`.
+pub(super) fn parse_autolink(text: &str) -> Option<usize> {
+    let inner = text.strip_prefix('<')?;
+    let end = inner.find(|c: char| c == '>' || c == '<' || c.is_whitespace())?;
+    if !inner[end..].starts_with('>') {
+        return None;
+    }
+    let target = &inner[..end];
+
+    let is_uri = target.split_once(':').is_some_and(|(scheme, _)| {
+        (2..=32).contains(&scheme.len())
+            && scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+            && scheme
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '.' | '-'))
+    });
+    let is_email = target.split_once('@').is_some_and(|(user, domain)| {
+        !user.is_empty()
+            && !domain.is_empty()
+            && !domain.contains('@')
+            && user
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || ".!#$%&'*+/=?^_`{|}~-".contains(c))
+            && domain
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-'))
+    });
+
+    (is_uri || is_email).then_some(end + 2)
+}
@@ -0,0 +1,60 @@
+pub(super) struct InlineMathMatch {
+    /// Length of the `$` or `$$` marker on each side.
+    pub marker_len: usize,
+    /// Total length of the math, including both markers.
+    pub len: usize,
+}
+
+/// Recognize inline math at the start of `text`, following Pandoc's
+/// `tex_math_dollars` rules.
+///
+/// For `$...$`, the opening `$` must not be followed by whitespace, the
+/// closing `$` must not be preceded by whitespace nor followed by a digit.
+/// `$$...$$` is display math and has no such restrictions. Inside the math,
+/// a backslash escapes the next character, so `\$` does not close it.
+pub(super) fn try_parse_inline_math(text: &str) -> Option<InlineMathMatch> {
+    let marker_len = if text.starts_with("$$") {
+        2
+    } else if text.starts_with('$') {
+        1
+    } else {
+        return None;
+    };
+
+    let content = &text[marker_len..];
+    if marker_len == 1 && content.starts_with(char::is_whitespace) {
+        return None;
+    }
+
+    let mut prev = None;
+    let mut chars = content.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            '$' if marker_len == 2 && i > 0 && content[i..].starts_with("$$") => {
+                return Some(InlineMathMatch {
+                    marker_len,
+                    len: marker_len + i + 2,
+                });
+            }
+            '$' if marker_len == 2 => {}
+            '$' => {
+                let next = content[i + 1..].chars().next();
+                if prev.is_some_and(|p: char| !p.is_whitespace())
+                    && !next.is_some_and(|n| n.is_ascii_digit())
+                {
+                    return Some(InlineMathMatch {
+                        marker_len,
+                        len: marker_len + i + 1,
+                    });
+                }
+            }
+            _ => {}
+        }
+        prev = Some(c);
+    }
+
+    None
+}
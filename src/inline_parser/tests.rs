@@ -1,27 +1,589 @@
 // Tests for inline parser functionality
 // These tests will be expanded as we implement inline parsing features
 
+#[cfg(test)]
+mod helpers {
+    use crate::block_parser::BlockParser;
+    use crate::inline_parser::InlineParser;
+    use crate::syntax::{SyntaxKind, SyntaxNode};
+
+    pub fn parse_inline(input: &str) -> SyntaxNode {
+        InlineParser::new(BlockParser::new(input).parse()).parse()
+    }
+
+    pub fn find_all(node: &SyntaxNode, kind: SyntaxKind) -> Vec<String> {
+        node.descendants()
+            .filter(|n| n.kind() == kind)
+            .map(|n| n.text().to_string())
+            .collect()
+    }
+
+    pub fn token_texts(node: &SyntaxNode, kind: SyntaxKind) -> Vec<String> {
+        node.descendants_with_tokens()
+            .filter_map(|el| el.into_token())
+            .filter(|t| t.kind() == kind)
+            .map(|t| t.text().to_string())
+            .collect()
+    }
+
+    pub fn assert_lossless(input: &str) {
+        let tree = parse_inline(input);
+        assert_eq!(tree.text().to_string(), input);
+    }
+}
+
 #[cfg(test)]
 mod emphasis_tests {
-    // TODO: Add tests for emphasis parsing (*text*, **text**, _text_, __text__)
+    use super::helpers::{assert_lossless, find_all, parse_inline};
+    use crate::syntax::SyntaxKind;
+
+    #[test]
+    fn parses_emphasis_and_strong() {
+        let tree = parse_inline("Some *emphasis* and **strong** text.\n");
+        assert_eq!(find_all(&tree, SyntaxKind::Emphasis), &["*emphasis*"]);
+        assert_eq!(find_all(&tree, SyntaxKind::Strong), &["**strong**"]);
+    }
+
+    #[test]
+    fn parses_underscore_emphasis() {
+        let tree = parse_inline("Some _emphasis_ and __strong__ text.\n");
+        assert_eq!(find_all(&tree, SyntaxKind::Emphasis), &["_emphasis_"]);
+        assert_eq!(find_all(&tree, SyntaxKind::Strong), &["__strong__"]);
+    }
+
+    #[test]
+    fn nested_strong_and_emphasis() {
+        let tree = parse_inline("***both*** and *a **b** c*\n");
+        assert_eq!(
+            find_all(&tree, SyntaxKind::Emphasis),
+            &["*both*", "*a **b** c*"]
+        );
+        assert_eq!(
+            find_all(&tree, SyntaxKind::Strong),
+            &["***both***", "**b**"]
+        );
+        assert_lossless("***both*** and *a **b** c*\n");
+    }
+
+    #[test]
+    fn emphasis_spans_lines() {
+        let tree = parse_inline("*one\ntwo*\n");
+        assert_eq!(find_all(&tree, SyntaxKind::Emphasis), &["*one\ntwo*"]);
+    }
+
+    #[test]
+    fn intraword_underscores_are_literal() {
+        let tree = parse_inline("snake_case_name and *intra*word\n");
+        assert!(find_all(&tree, SyntaxKind::Emphasis) == ["*intra*"]);
+    }
+
+    #[test]
+    fn delimiters_followed_by_space_do_not_open() {
+        let tree = parse_inline("a * b * c and 2*3*4 = 24\n");
+        assert_eq!(find_all(&tree, SyntaxKind::Emphasis), &["*3*"]);
+
+        let tree = parse_inline("a * not emphasis*\n");
+        assert!(find_all(&tree, SyntaxKind::Emphasis).is_empty());
+    }
+
+    #[test]
+    fn unmatched_delimiters_are_text() {
+        let input = "**not closed and *also not\n";
+        let tree = parse_inline(input);
+        assert!(find_all(&tree, SyntaxKind::Emphasis).is_empty());
+        assert!(find_all(&tree, SyntaxKind::Strong).is_empty());
+        assert_lossless(input);
+    }
+
+    #[test]
+    fn leftover_delimiters_stay_outside() {
+        let input = "***a*\n";
+        let tree = parse_inline(input);
+        assert_eq!(find_all(&tree, SyntaxKind::Emphasis), &["*a*"]);
+        assert_lossless(input);
+    }
+
+    #[test]
+    fn first_closer_ends_emphasis() {
+        // CommonMark would find strong emphasis in both
+        for input in ["**foo*bar**\n", "*foo**bar*\n"] {
+            let tree = parse_inline(input);
+            assert!(find_all(&tree, SyntaxKind::Emphasis).is_empty());
+            assert!(find_all(&tree, SyntaxKind::Strong).is_empty());
+            assert_lossless(input);
+        }
+    }
+
+    #[test]
+    fn closers_may_follow_whitespace() {
+        let tree = parse_inline("*foo * and **bar **\n");
+        assert_eq!(find_all(&tree, SyntaxKind::Emphasis), &["*foo *"]);
+        assert_eq!(find_all(&tree, SyntaxKind::Strong), &["**bar **"]);
+    }
+
+    #[test]
+    fn underscores_inside_emphasis_are_literal() {
+        let tree = parse_inline("_foo_bar_ and __a_b__\n");
+        assert_eq!(find_all(&tree, SyntaxKind::Emphasis), &["_foo_bar_"]);
+        assert_eq!(find_all(&tree, SyntaxKind::Strong), &["__a_b__"]);
+    }
+
+    #[test]
+    fn underscore_cannot_open_after_closer() {
+        let tree = parse_inline("*a*_b_\n");
+        assert_eq!(find_all(&tree, SyntaxKind::Emphasis), &["*a*"]);
+    }
 }
 
 #[cfg(test)]
 mod link_tests {
-    // TODO: Add tests for link parsing ([text](url), [text][ref])
+    use super::helpers::{assert_lossless, find_all, parse_inline, token_texts};
+    use crate::syntax::SyntaxKind;
+
+    #[test]
+    fn parses_inline_link() {
+        let input = "A [link *text*](https://example.com \"Title\") here.\n";
+        let tree = parse_inline(input);
+        assert_eq!(
+            find_all(&tree, SyntaxKind::Link),
+            &["[link *text*](https://example.com \"Title\")"]
+        );
+        assert_eq!(find_all(&tree, SyntaxKind::LinkText), &["link *text*"]);
+        assert_eq!(find_all(&tree, SyntaxKind::Emphasis), &["*text*"]);
+        assert_eq!(
+            token_texts(&tree, SyntaxKind::LinkUrl),
+            &["https://example.com"]
+        );
+        assert_eq!(token_texts(&tree, SyntaxKind::LinkTitle), &["\"Title\""]);
+        assert_lossless(input);
+    }
+
+    #[test]
+    fn parses_image_with_attributes() {
+        let tree = parse_inline("![A *figure*](fig.png){width=50%}\n");
+        assert_eq!(
+            find_all(&tree, SyntaxKind::ImageLink),
+            &["![A *figure*](fig.png){width=50%}"]
+        );
+        assert_eq!(token_texts(&tree, SyntaxKind::ImageLinkStart), &["!["]);
+        assert_eq!(find_all(&tree, SyntaxKind::Attribute), &["{width=50%}"]);
+    }
+
+    #[test]
+    fn link_text_and_destination_may_span_lines() {
+        let input = "[link\ntext](<my url>\n'title')\n";
+        let tree = parse_inline(input);
+        assert_eq!(find_all(&tree, SyntaxKind::LinkText), &["link\ntext"]);
+        assert_eq!(token_texts(&tree, SyntaxKind::LinkUrl), &["<my url>"]);
+        assert_eq!(token_texts(&tree, SyntaxKind::LinkTitle), &["'title'"]);
+        assert_lossless(input);
+    }
+
+    #[test]
+    fn url_with_parentheses() {
+        let tree = parse_inline("[wiki](https://en.wikipedia.org/wiki/Rust_(language)).\n");
+        assert_eq!(
+            token_texts(&tree, SyntaxKind::LinkUrl),
+            &["https://en.wikipedia.org/wiki/Rust_(language)"]
+        );
+    }
+
+    #[test]
+    fn parses_autolinks() {
+        let tree = parse_inline("<https://example.com> and <me@example.com> but not <b>\n");
+        assert_eq!(
+            find_all(&tree, SyntaxKind::AutoLink),
+            &["<https://example.com>", "<me@example.com>"]
+        );
+    }
+
+    #[test]
+    fn parses_reference_links() {
+        let input = "[text][Ref], [ref][] and [REF].\n\n[ref]: https://example.com\n";
+        let tree = parse_inline(input);
+        assert_eq!(
+            find_all(&tree, SyntaxKind::Link),
+            &["[text][Ref]", "[ref][]", "[REF]"]
+        );
+        assert_eq!(find_all(&tree, SyntaxKind::LinkRef), &["[Ref]", "[]"]);
+        assert_lossless(input);
+    }
+
+    #[test]
+    fn references_defined_in_nested_blocks_resolve() {
+        let input = "See [docs].\n\n- Item\n\n  [docs]: https://example.com\n";
+        let tree = parse_inline(input);
+        assert_eq!(find_all(&tree, SyntaxKind::Link), &["[docs]"]);
+    }
+
+    #[test]
+    fn definition_lines_inside_paragraphs_define_nothing() {
+        let tree = parse_inline("See [docs].\n[docs]: https://example.com\n");
+        assert!(find_all(&tree, SyntaxKind::Link).is_empty());
+    }
+
+    #[test]
+    fn undefined_references_are_text() {
+        let tree = parse_inline("[text][nope] and [nope] and [x]: y\n");
+        assert!(find_all(&tree, SyntaxKind::Link).is_empty());
+    }
+
+    #[test]
+    fn parses_bracketed_span() {
+        let tree = parse_inline("A [small **caps**]{.smallcaps} span.\n");
+        assert_eq!(
+            find_all(&tree, SyntaxKind::BracketedSpan),
+            &["[small **caps**]{.smallcaps}"]
+        );
+        assert_eq!(
+            find_all(&tree, SyntaxKind::SpanContent),
+            &["small **caps**"]
+        );
+        assert_eq!(find_all(&tree, SyntaxKind::Strong), &["**caps**"]);
+    }
+
+    #[test]
+    fn brackets_in_code_spans_and_escapes_do_not_close() {
+        let tree = parse_inline("[a `]` \\] b](url)\n");
+        assert_eq!(find_all(&tree, SyntaxKind::LinkText), &["a `]` \\] b"]);
+    }
+
+    #[test]
+    fn emphasis_does_not_cross_link_boundaries() {
+        let tree = parse_inline("*a [b* c](url)\n");
+        assert!(find_all(&tree, SyntaxKind::Emphasis).is_empty());
+        assert_eq!(find_all(&tree, SyntaxKind::Link), &["[b* c](url)"]);
+    }
 }
 
 #[cfg(test)]
 mod code_tests {
-    // TODO: Add tests for inline code parsing (`code`)
+    use super::helpers::{assert_lossless, find_all, parse_inline, token_texts};
+    use crate::syntax::SyntaxKind;
+
+    #[test]
+    fn parses_code_span() {
+        let tree = parse_inline("Use `x <- 1` here.\n");
+        assert_eq!(find_all(&tree, SyntaxKind::CodeSpan), &["`x <- 1`"]);
+        assert_eq!(token_texts(&tree, SyntaxKind::CodeSpanMarker), &["`", "`"]);
+    }
+
+    #[test]
+    fn parses_code_span_with_longer_backtick_run() {
+        let tree = parse_inline("Write `` a ` b `` inline.\n");
+        assert_eq!(find_all(&tree, SyntaxKind::CodeSpan), &["`` a ` b ``"]);
+    }
+
+    #[test]
+    fn code_span_content_is_not_parsed() {
+        let tree = parse_inline("`*not emphasis* \\*`\n");
+        assert!(find_all(&tree, SyntaxKind::Emphasis).is_empty());
+        assert!(token_texts(&tree, SyntaxKind::Escape).is_empty());
+    }
+
+    #[test]
+    fn code_span_takes_precedence_over_emphasis() {
+        let tree = parse_inline("*a `b*` c\n");
+        assert!(find_all(&tree, SyntaxKind::Emphasis).is_empty());
+        assert_eq!(find_all(&tree, SyntaxKind::CodeSpan), &["`b*`"]);
+    }
+
+    #[test]
+    fn unmatched_backticks_are_text() {
+        let input = "A ``lonely` tick\n";
+        let tree = parse_inline(input);
+        assert!(find_all(&tree, SyntaxKind::CodeSpan).is_empty());
+        assert_lossless(input);
+    }
+}
+
+#[cfg(test)]
+mod footnote_tests {
+    use super::helpers::{assert_lossless, find_all, parse_inline, token_texts};
+    use crate::syntax::SyntaxKind;
+
+    #[test]
+    fn parses_inline_footnote() {
+        let input = "Text.^[A *note* with [a link](url).] More.\n";
+        let tree = parse_inline(input);
+        assert_eq!(
+            find_all(&tree, SyntaxKind::InlineFootnote),
+            &["^[A *note* with [a link](url).]"]
+        );
+        assert_eq!(find_all(&tree, SyntaxKind::Emphasis), &["*note*"]);
+        assert_eq!(find_all(&tree, SyntaxKind::Link), &["[a link](url)"]);
+        assert_lossless(input);
+    }
+
+    #[test]
+    fn parses_footnote_references() {
+        let tree = parse_inline("Text.[^1] More[^note-2].\n\n[^1]: One.\n");
+        assert_eq!(
+            token_texts(&tree, SyntaxKind::FootnoteReference),
+            &["[^1]", "[^note-2]"]
+        );
+        assert!(find_all(&tree, SyntaxKind::Link).is_empty());
+    }
+
+    #[test]
+    fn caret_without_bracket_is_text() {
+        let tree = parse_inline("x^2 and ^[unclosed\n");
+        assert!(find_all(&tree, SyntaxKind::InlineFootnote).is_empty());
+        assert_lossless("x^2 and ^[unclosed\n");
+    }
+}
+
+#[cfg(test)]
+mod citation_tests {
+    use super::helpers::{assert_lossless, find_all, parse_inline, token_texts};
+    use crate::syntax::SyntaxKind;
+
+    #[test]
+    fn parses_citation_group() {
+        let input = "As shown [see @doe99, pp. 33-35; also -@smith04, chap. 1].\n";
+        let tree = parse_inline(input);
+        assert_eq!(
+            find_all(&tree, SyntaxKind::Citation),
+            &["[see @doe99, pp. 33-35; also -@smith04, chap. 1]"]
+        );
+        assert_eq!(
+            find_all(&tree, SyntaxKind::CitationItem),
+            &["see @doe99, pp. 33-35", "also -@smith04, chap. 1"]
+        );
+        assert_eq!(token_texts(&tree, SyntaxKind::CitationMarker), &["@", "-@"]);
+        assert_eq!(
+            token_texts(&tree, SyntaxKind::CitationKey),
+            &["doe99", "smith04"]
+        );
+        assert_lossless(input);
+    }
+
+    #[test]
+    fn parses_in_text_citations() {
+        let tree = parse_inline("See @fig-plot and @eq-model.\n");
+        assert_eq!(
+            find_all(&tree, SyntaxKind::Citation),
+            &["@fig-plot", "@eq-model"]
+        );
+        assert_eq!(
+            token_texts(&tree, SyntaxKind::CitationKey),
+            &["fig-plot", "eq-model"]
+        );
+    }
+
+    #[test]
+    fn parses_braced_keys() {
+        let tree = parse_inline("See @{weird key} and [@{other}, p. 1].\n");
+        assert_eq!(
+            token_texts(&tree, SyntaxKind::CitationKey),
+            &["{weird key}", "{other}"]
+        );
+    }
+
+    #[test]
+    fn email_addresses_are_not_citations() {
+        let tree = parse_inline("Mail me@example.com or [me@example.com].\n");
+        assert!(find_all(&tree, SyntaxKind::Citation).is_empty());
+    }
+
+    #[test]
+    fn every_item_needs_a_key() {
+        let tree = parse_inline("[see @doe99; and more]\n");
+        assert_eq!(find_all(&tree, SyntaxKind::Citation), &["@doe99"]);
+    }
 }
 
 #[cfg(test)]
 mod math_tests {
-    // TODO: Add tests for inline math parsing ($math$)
+    use super::helpers::{assert_lossless, find_all, parse_inline, token_texts};
+    use crate::syntax::SyntaxKind;
+
+    #[test]
+    fn parses_inline_math() {
+        let tree = parse_inline("Let $a + b = c$ hold.\n");
+        assert_eq!(find_all(&tree, SyntaxKind::InlineMath), &["$a + b = c$"]);
+        assert_eq!(
+            token_texts(&tree, SyntaxKind::InlineMathMarker),
+            &["$", "$"]
+        );
+    }
+
+    #[test]
+    fn parses_inline_display_math() {
+        let tree = parse_inline("Then $$ x^2 $$ follows.\n");
+        assert_eq!(find_all(&tree, SyntaxKind::InlineMath), &["$$ x^2 $$"]);
+    }
+
+    #[test]
+    fn math_may_span_lines() {
+        let input = "Let $a +\nb$ hold.\n";
+        assert_eq!(
+            find_all(&parse_inline(input), SyntaxKind::InlineMath),
+            &["$a +\nb$"]
+        );
+        assert_lossless(input);
+    }
+
+    #[test]
+    fn whitespace_after_opening_or_before_closing_is_not_math() {
+        let tree = parse_inline("$ a$ and $a $ and $ a $\n");
+        assert!(find_all(&tree, SyntaxKind::InlineMath).is_empty());
+    }
+
+    #[test]
+    fn closing_dollar_followed_by_digit_is_not_math() {
+        let tree = parse_inline("It costs $20,000 and $30,000.\n");
+        assert!(find_all(&tree, SyntaxKind::InlineMath).is_empty());
+
+        let tree = parse_inline("From $a$1 to $b$.\n");
+        assert_eq!(find_all(&tree, SyntaxKind::InlineMath), &["$a$1 to $b$"]);
+    }
+
+    #[test]
+    fn escaped_dollars() {
+        let tree = parse_inline("Costs \\$5 or \\$6.\n");
+        assert!(find_all(&tree, SyntaxKind::InlineMath).is_empty());
+        assert_eq!(token_texts(&tree, SyntaxKind::Escape), &["\\$", "\\$"]);
+
+        let tree = parse_inline("$a \\$ b$\n");
+        assert_eq!(find_all(&tree, SyntaxKind::InlineMath), &["$a \\$ b$"]);
+    }
+
+    #[test]
+    fn math_content_is_not_parsed() {
+        let tree = parse_inline("$a_1 * b_2 * c$\n");
+        assert!(find_all(&tree, SyntaxKind::Emphasis).is_empty());
+    }
 }
 
 #[cfg(test)]
 mod escape_tests {
-    // TODO: Add tests for escape sequence parsing (\*)
-}
\ No newline at end of file
+    use super::helpers::{find_all, parse_inline, token_texts};
+    use crate::syntax::SyntaxKind;
+
+    #[test]
+    fn escaped_delimiters_do_not_open_emphasis() {
+        let tree = parse_inline("\\*not emphasis\\*\n");
+        assert!(find_all(&tree, SyntaxKind::Emphasis).is_empty());
+        assert_eq!(token_texts(&tree, SyntaxKind::Escape), &["\\*", "\\*"]);
+    }
+
+    #[test]
+    fn escaped_backtick_does_not_open_code_span() {
+        let tree = parse_inline("\\`not code`\n");
+        assert!(find_all(&tree, SyntaxKind::CodeSpan).is_empty());
+    }
+
+    #[test]
+    fn backslash_before_letter_is_text() {
+        let tree = parse_inline("C:\\path\n");
+        assert!(token_texts(&tree, SyntaxKind::Escape).is_empty());
+    }
+
+    #[test]
+    fn hard_line_breaks() {
+        let tree = parse_inline("one\\\ntwo  \nthree\n");
+        assert_eq!(token_texts(&tree, SyntaxKind::HardLineBreak), &["\\", "  "]);
+    }
+
+    #[test]
+    fn trailing_spaces_at_paragraph_end_are_not_a_break() {
+        let tree = parse_inline("one  \n");
+        assert!(token_texts(&tree, SyntaxKind::HardLineBreak).is_empty());
+    }
+}
+
+#[cfg(test)]
+mod html_tests {
+    use super::helpers::{assert_lossless, find_all, parse_inline, token_texts};
+    use crate::syntax::SyntaxKind;
+
+    #[test]
+    fn parses_inline_tags() {
+        let tree = parse_inline("Some <span class=\"x y\" data-id=1>styled</span> text<br/>\n");
+        assert_eq!(
+            find_all(&tree, SyntaxKind::InlineHtml),
+            &["<span class=\"x y\" data-id=1>", "</span>", "<br/>"]
+        );
+    }
+
+    #[test]
+    fn tag_may_span_lines() {
+        let input = "A <span\nclass=\"x\">span</span>.\n";
+        let tree = parse_inline(input);
+        assert_eq!(
+            find_all(&tree, SyntaxKind::InlineHtml),
+            &["<span\nclass=\"x\">", "</span>"]
+        );
+        assert_lossless(input);
+    }
+
+    #[test]
+    fn parses_inline_comments() {
+        let tree = parse_inline("Text <!-- a *comment* --> more.\n");
+        assert_eq!(
+            find_all(&tree, SyntaxKind::Comment),
+            &["<!-- a *comment* -->"]
+        );
+        assert!(find_all(&tree, SyntaxKind::Emphasis).is_empty());
+        assert_eq!(token_texts(&tree, SyntaxKind::CommentStart), &["<!--"]);
+        assert_eq!(token_texts(&tree, SyntaxKind::CommentEnd), &["-->"]);
+    }
+
+    #[test]
+    fn less_than_is_not_html() {
+        for input in [
+            "a < b and c > d\n",
+            "I <3 you\n",
+            "if a<b then\n",
+            "<a href=>\n",
+        ] {
+            let tree = parse_inline(input);
+            assert!(
+                find_all(&tree, SyntaxKind::InlineHtml).is_empty(),
+                "{input:?}"
+            );
+            assert_lossless(input);
+        }
+    }
+
+    #[test]
+    fn unclosed_comment_is_text() {
+        let tree = parse_inline("Text <!-- not closed\n");
+        assert!(find_all(&tree, SyntaxKind::Comment).is_empty());
+    }
+}
+
+#[cfg(test)]
+mod latex_tests {
+    use super::helpers::{assert_lossless, find_all, parse_inline};
+    use crate::syntax::SyntaxKind;
+
+    #[test]
+    fn parses_inline_commands() {
+        let tree =
+            parse_inline("Text \\textbf{bold {nested} text} and \\LaTeX and \\cite[p.~3]{key}.\n");
+        assert_eq!(
+            find_all(&tree, SyntaxKind::LatexCommand),
+            &[
+                "\\textbf{bold {nested} text}",
+                "\\LaTeX",
+                "\\cite[p.~3]{key}"
+            ]
+        );
+    }
+
+    #[test]
+    fn unbalanced_braces_are_not_a_command() {
+        let input = "Text \\textbf{bold text\n";
+        let tree = parse_inline(input);
+        assert!(find_all(&tree, SyntaxKind::LatexCommand).is_empty());
+        assert_lossless(input);
+    }
+
+    #[test]
+    fn escapes_are_not_commands() {
+        let tree = parse_inline("A \\* star and \\\\ backslash\n");
+        assert!(find_all(&tree, SyntaxKind::LatexCommand).is_empty());
+    }
+}
@@ -6,32 +6,44 @@ pub mod syntax;
 
 pub use config::Config;
 pub use config::ConfigBuilder;
+pub use config::GridTableMode;
+pub use config::HeadingStyle;
+pub use config::IndentedCodeMode;
+pub use config::LineEnding;
+pub use config::LinkReferenceMode;
+pub use config::ThematicBreakStyle;
 pub use formatter::format_tree;
 
 fn init_logger() {
     let _ = env_logger::builder().is_test(true).try_init();
 }
 
-fn detect_line_ending(input: &str) -> &str {
-    // Check for first occurrence of \r\n or \n
-    let rn_pos = input.find("\r\n");
-    let n_pos = input.find('\n');
-
-    if let (Some(rn), Some(n)) = (rn_pos, n_pos) {
-        if rn < n {
-            return "\r\n";
-        }
-    } else if rn_pos.is_some() {
-        return "\r\n";
+/// The line ending used by the first line of `input`, or `\n` if the input
+/// is a single line.
+fn detect_line_ending(input: &str) -> &'static str {
+    match input.find('\n') {
+        Some(n) if input[..n].ends_with('\r') => "\r\n",
+        _ => "\n",
     }
+}
 
-    "\n"
+/// The line ending to write, according to the configuration. With
+/// [`LineEnding::Auto`] (the default), the line ending of the first line in
+/// the input is used throughout, so input with mixed line endings is
+/// normalized to whichever comes first.
+fn output_line_ending(input: &str, config: &Config) -> &'static str {
+    match config.line_ending {
+        Some(LineEnding::Lf) => "\n",
+        Some(LineEnding::Crlf) => "\r\n",
+        Some(LineEnding::Auto) | None => detect_line_ending(input),
+    }
 }
 
 /// Formats a Quarto document string with the specified line width.
 ///
 /// This function normalizes line endings, preserves code blocks and frontmatter,
-/// and applies consistent paragraph wrapping.
+/// and applies consistent paragraph wrapping. The output uses the line ending
+/// selected by `Config::line_ending`.
 ///
 /// # Examples
 ///
@@ -47,14 +59,15 @@ fn detect_line_ending(input: &str) -> &str {
 /// # Arguments
 ///
 /// * `input` - The Quarto document content to format
-/// * `line_width` - Optional line width (defaults to 80)
+/// * `config` - Optional configuration (defaults to `Config::default()`)
 pub fn format(input: &str, config: Option<Config>) -> String {
     #[cfg(debug_assertions)]
     {
         init_logger();
     }
 
-    let line_ending = detect_line_ending(input);
+    let config = config.unwrap_or_default();
+    let line_ending = output_line_ending(input, &config);
 
     let normalized_input = input.replace("\r\n", "\n");
 
@@ -65,7 +78,6 @@ pub fn format(input: &str, config: Option<Config>) -> String {
     let tree = inline_parser::InlineParser::new(block_tree).parse();
 
     // Step 3: Format the final CST
-    let config = config.unwrap_or_default();
     let out = format_tree(&tree, &config);
 
     if line_ending == "\r\n" {
@@ -11,17 +11,33 @@ pub enum SyntaxKind {
     FrontmatterDelim, // --- or +++
     BlockQuoteMarker, // >
     ImageLinkStart,   // ![
-    ListMarker,       // - + *
+    ListMarker,       // - + * 1. a) (i)
     CommentStart,     // <!--
     CommentEnd,       // -->
     Attribute,        // {#label} for headings, math, etc.
-    HorizontalRule,   // --- or *** or ___
+    AttributeId,      // #id
+    AttributeClass,   // .class
+    AttributeKeyValue,
+    AttributeKey,
+    AttributeValue,
+    HorizontalRule, // --- or *** or ___
     BlankLine,
 
     // Links
-    LinkStart, // [
-    Link,      // [text](url)
-    ImageLink, // ![alt](url)
+    LinkStart,               // [
+    Link,                    // [text](url)
+    ImageLink,               // ![alt](url)
+    LinkText,                // text or alt inside the brackets
+    LinkTextEnd,             // ]
+    LinkDest,                // (url "title")
+    LinkUrl,                 // url
+    LinkTitle,               // "title"
+    LinkRef,                 // [ref] or [] of a reference link
+    LinkReferenceDefinition, // [ref]: url "title"
+    LinkReferenceLabel,      // [ref]
+    AutoLink,                // <https://...>
+    BracketedSpan,           // [text]{.class}
+    SpanContent,
 
     // Math
     InlineMathMarker, // $
@@ -31,25 +47,59 @@ pub enum SyntaxKind {
     MathContent,
 
     // Footnotes
-    InlineFootnoteStart, // ^[
-    InlineFootnoteEnd,   // ]
-    InlineFootnote,      // ^[text]
+    InlineFootnoteStart,      // ^[
+    InlineFootnoteEnd,        // ]
+    InlineFootnote,           // ^[text]
+    FootnoteReference,        // [^1]
+    FootnoteDefinition,       // [^1]: text
+    FootnoteDefinitionMarker, // [^1]:
+    FootnoteContent,
+
+    // Citations
+    Citation,          // [see @doe99, p. 33; -@smith04] or @doe99
+    CitationItem,      // see @doe99, p. 33
+    CitationMarker,    // @ or -@
+    CitationKey,       // doe99 or {weird key}
+    CitationSeparator, // ;
+
+    // Emphasis
+    Emphasis,
+    Strong,
+    EmphasisMarker, // * or _
+    StrongMarker,   // ** or __
+
+    // Escapes and line breaks
+    Escape,        // \*
+    HardLineBreak, // trailing \ or two spaces
 
     // Code
     CodeSpan,
+    CodeSpanMarker,  // ` or ``
     CodeFenceMarker, // ``` or ~~~
     CodeBlock,
+    IndentedCodeBlock,
 
     // Composite nodes
     ROOT,
     DOCUMENT,
     FRONTMATTER,
+    FrontmatterContent,
     FencedDiv,
     PARAGRAPH,
     BlockQuote,
     List,
     ListItem,
+    ItemContent,
+    DefinitionList,
+    DefinitionItem,
+    Term,
+    Definition,
+    DefinitionMarker, // : or ~
+    LineBlock,
+    LineBlockMarker, // |
     Comment,
+    HtmlBlock,
+    InlineHtml, // <span class="x"> or </span>
 
     // Headings
     Heading,
@@ -66,12 +116,31 @@ pub enum SyntaxKind {
 
     // Tables
     SimpleTable,
+    MultilineTable,
+    PipeTable,
+    TableHeader,
+    TableSeparator, // |:---|---:|
+    TableRow,
+    TableCell,
+    TableAlignment,     // :---:
+    TablePipe,          // |
+    TableCaption,       // : Caption {#tbl-id}
+    TableCaptionMarker, // : or Table:
+    GridTable,
+    GridTableBorder, // +---+---+ or +===+===+
+    GridTableRow,    // | a | b |
 
     // Code block parts
     CodeFenceOpen,
     CodeFenceClose,
     CodeInfo,
     CodeContent,
+    CodeCell, // ```{r} ... ```
+    CellOptions,
+    CellOption,       // #| key: value
+    CellOptionMarker, // #|, //| or --|
+    CellOptionKey,
+    CellOptionValue,
 
     // Div parts
     DivFenceOpen,
@@ -0,0 +1,25 @@
+use quartofmt::{ConfigBuilder, format};
+
+#[test]
+fn citation_group_is_never_broken() {
+    let cfg = ConfigBuilder::default().line_width(20).build();
+    let input = "This was shown [see @doe99, pp. 33-35] before.\n";
+    let output = format(input, Some(cfg));
+    similar_asserts::assert_eq!(output, "This was shown\n[see @doe99, pp. 33-35]\nbefore.\n");
+}
+
+#[test]
+fn citation_spacing_is_normalized() {
+    let input = "As shown [@doe99 ;@smith04,  p. 1;\n-@roe05].\n";
+    let output = format(input, None);
+    similar_asserts::assert_eq!(output, "As shown [@doe99; @smith04, p. 1; -@roe05].\n");
+}
+
+#[test]
+fn cross_references_stay_attached() {
+    let cfg = ConfigBuilder::default().line_width(10).build();
+    let input = "See (@fig-plot) and @eq-model.\n";
+    let output = format(input, Some(cfg));
+    assert!(output.contains("(@fig-plot)"));
+    assert!(output.contains("@eq-model."));
+}
@@ -0,0 +1,94 @@
+use quartofmt::{ConfigBuilder, IndentedCodeMode, format};
+
+#[test]
+fn indented_code_is_preserved() {
+    let cfg = ConfigBuilder::default().line_width(8).build();
+    let input = "Some text.\n\n    let x   = 1;\n\n    let y = [1, 2, 3];\n\nMore text.\n";
+    let expected = "Some\ntext.\n\n    let x   = 1;\n\n    let y = [1, 2, 3];\n\nMore\ntext.\n";
+    let output = format(input, Some(cfg));
+    similar_asserts::assert_eq!(output, expected);
+}
+
+#[test]
+fn indented_code_is_converted_to_fenced() {
+    let cfg = ConfigBuilder::default()
+        .indented_code_blocks(IndentedCodeMode::Fenced)
+        .build();
+    let input = "Text.\n\n    fn main() {\n  \n        println!();\n    }\n\nMore.\n";
+    let expected = "Text.\n\n```\nfn main() {\n\n    println!();\n}\n```\n\nMore.\n";
+    let output = format(input, Some(cfg.clone()));
+    similar_asserts::assert_eq!(output, expected);
+
+    let output_twice = format(&output, Some(cfg));
+    similar_asserts::assert_eq!(output_twice, expected);
+}
+
+#[test]
+fn fenced_conversion_uses_longer_fence() {
+    let cfg = ConfigBuilder::default()
+        .indented_code_blocks(IndentedCodeMode::Fenced)
+        .build();
+    let input = "    ```\n    nested\n    ```\n";
+    let expected = "````\n```\nnested\n```\n````\n";
+    let output = format(input, Some(cfg));
+    similar_asserts::assert_eq!(output, expected);
+}
+
+#[test]
+fn fenced_conversion_counts_indented_backtick_runs() {
+    let cfg = ConfigBuilder::default()
+        .indented_code_blocks(IndentedCodeMode::Fenced)
+        .build();
+    let input = "    code\n     ```\n    more ```\n\nText.\n";
+    let expected = "````\ncode\n ```\nmore ```\n````\n\nText.\n";
+    let output = format(input, Some(cfg.clone()));
+    similar_asserts::assert_eq!(output, expected);
+    similar_asserts::assert_eq!(format(&output, Some(cfg)), expected);
+}
+
+#[test]
+fn fenced_conversion_keeps_trailing_whitespace() {
+    let cfg = ConfigBuilder::default()
+        .indented_code_blocks(IndentedCodeMode::Fenced)
+        .build();
+    let input = "    line one  \n    line two\t\n";
+    let expected = "```\nline one  \nline two\t\n```\n";
+    let output = format(input, Some(cfg));
+    similar_asserts::assert_eq!(output, expected);
+}
+
+#[test]
+fn indented_code_in_list_item_is_preserved() {
+    let input = "- item\n\n      code  here\n";
+    let output = format(input, None);
+    similar_asserts::assert_eq!(output, input);
+}
+
+#[test]
+fn fenced_code_is_preserved_verbatim() {
+    let input = "```{r}\nx   <-    1\n\n  y <- c(1,2)   \n```\n";
+    let output = format(input, None);
+    similar_asserts::assert_eq!(output, input);
+}
+
+#[test]
+fn tilde_fence_with_attributes_is_preserved() {
+    let input = "~~~~ {.python #lst-one}\nprint(1)\n~~~~\n";
+    let output = format(input, None);
+    similar_asserts::assert_eq!(output, input);
+}
+
+#[test]
+fn code_block_interrupting_paragraph_is_kept() {
+    let input = "Some text\n```\ncode\n```\n";
+    let output = format(input, None);
+    similar_asserts::assert_eq!(output, format(&output, None));
+    assert!(output.contains("```\ncode\n```\n"));
+}
+
+#[test]
+fn code_cell_options_are_preserved() {
+    let input = "Text.\n\n```{r}\n#| label: fig-plot\n#| fig-cap:\n#|   - \"A\"\n#|echo:   false\n\nplot(1:10)\n```\n";
+    let output = format(input, None);
+    similar_asserts::assert_eq!(output, input);
+}
@@ -28,3 +28,19 @@ fn comment_no_wrap() {
         "<!-- This is a very long comment that should not be wrapped or reformatted -->"
     ));
 }
+
+#[test]
+fn inline_comment_is_atomic() {
+    let cfg = quartofmt::ConfigBuilder::default().line_width(20).build();
+    let input = "Before <!-- a comment with words --> after.\n";
+    let expected = "Before\n<!-- a comment with words -->\nafter.\n";
+    let output = format(input, Some(cfg));
+    assert_eq!(output, expected);
+}
+
+#[test]
+fn multiline_comment_block_is_preserved() {
+    let input = "<!--\nA   comment\n\nspanning lines\n-->\n\nText.\n";
+    let output = format_with_defaults(input);
+    assert_eq!(output, input);
+}
@@ -0,0 +1,61 @@
+use quartofmt::{ConfigBuilder, format};
+
+#[test]
+fn compact_definition_list_is_normalized() {
+    let input = "Term 1\n: Definition 1\n\nTerm 2\n  ~ Definition 2a\n  ~ Definition 2b\n";
+    let expected = "Term 1\n:   Definition 1\n\nTerm 2\n:   Definition 2a\n:   Definition 2b\n";
+    let output = format(input, None);
+    similar_asserts::assert_eq!(output, expected);
+}
+
+#[test]
+fn loose_definition_keeps_blank_line() {
+    let input = "Term\n\n  ~ Definition\n";
+    let expected = "Term\n\n:   Definition\n";
+    let output = format(input, None);
+    similar_asserts::assert_eq!(output, expected);
+}
+
+#[test]
+fn definition_wraps_with_hanging_indent() {
+    let cfg = ConfigBuilder::default().line_width(30).build();
+    let input = "Term\n:   This definition is long enough that it has to be wrapped.\n";
+    let expected =
+        "Term\n:   This definition is long\n    enough that it has to be\n    wrapped.\n";
+    let output = format(input, Some(cfg.clone()));
+    similar_asserts::assert_eq!(output, expected);
+    similar_asserts::assert_eq!(format(&output, Some(cfg)), expected);
+}
+
+#[test]
+fn definition_with_several_blocks() {
+    let input = "Term\n\n~ First paragraph\nlazily continued.\n\n      Second paragraph.\n\n        code  here\n\nAfter.\n";
+    let expected = "Term\n\n:   First paragraph lazily continued.\n\n    Second paragraph.\n\n        code  here\n\nAfter.\n";
+    let output = format(input, None);
+    similar_asserts::assert_eq!(output, expected);
+    similar_asserts::assert_eq!(format(&output, None), expected);
+}
+
+#[test]
+fn items_get_one_blank_line_between_them() {
+    let input = "Apple\n:   A fruit.\n\n\nCarrot\n:   A vegetable.\n";
+    let expected = "Apple\n:   A fruit.\n\nCarrot\n:   A vegetable.\n";
+    let output = format(input, None);
+    similar_asserts::assert_eq!(output, expected);
+}
+
+#[test]
+fn term_is_not_merged_with_definition() {
+    let cfg = ConfigBuilder::default().line_width(80).build();
+    let input = "Glossary term\n:   Its meaning.\n";
+    let output = format(input, Some(cfg));
+    similar_asserts::assert_eq!(output, input);
+}
+
+#[test]
+fn compact_items_stay_separate() {
+    let input = "Term 1\n:   Def 1\nTerm 2\n:   Def 2\n";
+    let expected = "Term 1\n:   Def 1\n\nTerm 2\n:   Def 2\n";
+    let output = format(input, None);
+    similar_asserts::assert_eq!(output, expected);
+}
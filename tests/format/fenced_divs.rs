@@ -0,0 +1,24 @@
+use quartofmt::format;
+
+#[test]
+fn div_content_is_reflowed() {
+    let input = "::: {.callout-note}\nThis paragraph\nspans lines.\n:::\n";
+    let expected = "::: {.callout-note}\nThis paragraph spans lines.\n:::\n";
+    let output = format(input, None);
+    similar_asserts::assert_eq!(output, expected);
+    similar_asserts::assert_eq!(format(&output, None), expected);
+}
+
+#[test]
+fn nested_divs_roundtrip() {
+    let input = ":::: {.columns}\n\n::: {.column width=\"50%\"}\nLeft\n:::\n\n::: {.column width=\"50%\"}\nRight\n:::\n\n::::\n\nAfter the columns.\n";
+    let output = format(input, None);
+    similar_asserts::assert_eq!(output, input);
+}
+
+#[test]
+fn unclosed_div_keeps_content() {
+    let input = "::: {.callout-warning}\nNo closing fence\n";
+    let output = format(input, None);
+    similar_asserts::assert_eq!(output, input);
+}
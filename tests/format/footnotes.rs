@@ -0,0 +1,45 @@
+use quartofmt::{ConfigBuilder, format};
+
+#[test]
+fn footnote_definition_wraps_with_hanging_indent() {
+    let cfg = ConfigBuilder::default().line_width(30).build();
+    let input = "[^1]: This footnote is long enough that it has to be wrapped.\n";
+    let expected = "[^1]: This footnote is long\n    enough that it has to be\n    wrapped.\n";
+    let output = format(input, Some(cfg.clone()));
+    similar_asserts::assert_eq!(output, expected);
+    similar_asserts::assert_eq!(format(&output, Some(cfg)), expected);
+}
+
+#[test]
+fn footnote_continuation_paragraphs_are_indented() {
+    let input =
+        "[^1]: First paragraph\nwith a lazy line.\n\n      Second paragraph, over-indented.\n";
+    let expected =
+        "[^1]: First paragraph with a lazy line.\n\n    Second paragraph, over-indented.\n";
+    let output = format(input, None);
+    similar_asserts::assert_eq!(output, expected);
+}
+
+#[test]
+fn consecutive_footnotes_are_kept() {
+    let input = "Text.[^1][^2]\n\n[^1]: One.\n[^2]: Two.\n";
+    let output = format(input, None);
+    similar_asserts::assert_eq!(output, input);
+}
+
+#[test]
+fn inline_footnote_wraps_inside() {
+    let cfg = ConfigBuilder::default().line_width(30).build();
+    let input = "A sentence.^[With an inline note that needs wrapping.] End.\n";
+    let expected = "A sentence.^[With an inline\nnote that needs wrapping.]\nEnd.\n";
+    let output = format(input, Some(cfg));
+    similar_asserts::assert_eq!(output, expected);
+}
+
+#[test]
+fn footnote_reference_is_atomic() {
+    let cfg = ConfigBuilder::default().line_width(10).build();
+    let input = "Some words[^a-long-label] here.\n";
+    let output = format(input, Some(cfg));
+    assert!(output.contains("words[^a-long-label]"));
+}
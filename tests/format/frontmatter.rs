@@ -9,3 +9,26 @@ fn front_matter_and_paragraph() {
     assert!(output.contains("title: hi"));
     assert!(output.contains("Hello world"));
 }
+
+#[test]
+fn front_matter_is_preserved_verbatim() {
+    let cfg = quartofmt::ConfigBuilder::default().line_width(20).build();
+    let input = "---\ntitle: \"A rather long title that exceeds the width\"\nformat:\n  html:\n    toc: true\n---\n\nHello world\n";
+    let output = format(input, Some(cfg));
+    similar_asserts::assert_eq!(output, input);
+}
+
+#[test]
+fn metadata_block_later_in_document_is_preserved() {
+    let input = "Hello world\n\n---\nfoo: bar\nbaz: qux\n...\n\nMore text\n";
+    let output = format(input, None);
+    similar_asserts::assert_eq!(output, input);
+}
+
+#[test]
+fn dashes_around_prose_are_not_a_metadata_block() {
+    let cfg = quartofmt::ConfigBuilder::default().line_width(20).build();
+    let input = "Intro\n\n---\nSome text that is long enough to wrap.\n\nAnother paragraph.\n...\n";
+    let output = format(input, Some(cfg));
+    assert!(output.contains("Some text that is\nlong enough to wrap.\n"));
+}
@@ -1,4 +1,4 @@
-use quartofmt::format;
+use quartofmt::{ConfigBuilder, HeadingStyle, format};
 
 #[test]
 fn atx_trailing_hashes_are_removed() {
@@ -19,3 +19,77 @@ fn atx_leading_spaces_are_normalized() {
     assert_eq!(out, expected);
     assert_eq!(format(&out, None), expected);
 }
+
+#[test]
+fn setext_headings_become_atx_by_default() {
+    let input = "Title\n=====\n\nSubtitle\n---\nText\n";
+    let expected = "# Title\n\n## Subtitle\n\nText\n";
+    let out = format(input, None);
+    assert_eq!(out, expected);
+    assert_eq!(format(&out, None), expected);
+}
+
+#[test]
+fn setext_style_for_levels_one_and_two() {
+    let cfg = ConfigBuilder::default()
+        .heading_style(HeadingStyle::Setext)
+        .build();
+    let input = "# Title\n\n## A subtitle ##\n\n### Deeper\n";
+    let expected = "Title\n=====\n\nA subtitle\n----------\n\n### Deeper\n";
+    let out = format(input, Some(cfg.clone()));
+    assert_eq!(out, expected);
+    assert_eq!(format(&out, Some(cfg)), expected);
+}
+
+#[test]
+fn setext_underline_has_minimum_length() {
+    let cfg = ConfigBuilder::default()
+        .heading_style(HeadingStyle::Setext)
+        .build();
+    assert_eq!(format("# A\n", Some(cfg)), "A\n===\n");
+}
+
+#[test]
+fn preserve_heading_style() {
+    let cfg = ConfigBuilder::default()
+        .heading_style(HeadingStyle::Preserve)
+        .build();
+    let input = "Title\n=\n\n## ATX\n\nLearning C#\n---\n";
+    let expected = "Title\n=====\n\n## ATX\n\nLearning C#\n-----------\n";
+    let out = format(input, Some(cfg));
+    assert_eq!(out, expected);
+}
+
+#[test]
+fn heading_attributes_are_normalized() {
+    let input = "## Results   {  width=\"50%\" .appendix #sec-results  .unnumbered }\n";
+    let expected = "## Results {#sec-results .appendix .unnumbered width=\"50%\"}\n";
+    let out = format(input, None);
+    assert_eq!(out, expected);
+    assert_eq!(format(&out, None), expected);
+}
+
+#[test]
+fn setext_heading_attributes_are_kept() {
+    let input = "Introduction {.unnumbered #sec-intro}\n===\n";
+    let expected = "# Introduction {#sec-intro .unnumbered}\n";
+    assert_eq!(format(input, None), expected);
+}
+
+#[test]
+fn braces_that_are_not_attributes_are_kept() {
+    let input = "# Sets {a, b}\n";
+    assert_eq!(format(input, None), input);
+}
+
+#[test]
+fn setext_underline_ignores_attributes() {
+    let cfg = ConfigBuilder::default()
+        .heading_style(HeadingStyle::Setext)
+        .build();
+    let input = "## Results {#sec-results .appendix}\n";
+    let expected = "Results {#sec-results .appendix}\n-------\n";
+    let out = format(input, Some(cfg.clone()));
+    assert_eq!(out, expected);
+    assert_eq!(format(&out, Some(cfg)), expected);
+}
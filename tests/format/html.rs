@@ -0,0 +1,28 @@
+use quartofmt::{ConfigBuilder, format};
+
+#[test]
+fn html_blocks_are_preserved() {
+    let cfg = ConfigBuilder::default().line_width(20).build();
+    let input = "<div class=\"note\">\n\nSome   text that would otherwise be wrapped.\n\n</div>\n\n<script>\nlet  x = 1;\n\nlet y;\n</script>\n";
+    let output = format(input, Some(cfg));
+    similar_asserts::assert_eq!(output, input);
+}
+
+#[test]
+fn inline_tags_are_not_broken() {
+    let cfg = ConfigBuilder::default().line_width(20).build();
+    let input = "Some text with <span class=\"highlight important\">a span</span> inside.\n";
+    let expected = "Some text with\n<span class=\"highlight important\">a\nspan</span> inside.\n";
+    let output = format(input, Some(cfg.clone()));
+    similar_asserts::assert_eq!(output, expected);
+    similar_asserts::assert_eq!(format(&output, Some(cfg)), expected);
+}
+
+#[test]
+fn tags_spanning_lines_are_joined() {
+    let input = "Some <span\n  class=\"x\"   id=\"a  b\">text</span>.\n";
+    let expected = "Some <span class=\"x\" id=\"a  b\">text</span>.\n";
+    let output = format(input, None);
+    similar_asserts::assert_eq!(output, expected);
+    similar_asserts::assert_eq!(format(&output, None), expected);
+}
@@ -0,0 +1,25 @@
+use quartofmt::{ConfigBuilder, format};
+
+#[test]
+fn line_block_is_not_reflowed() {
+    let cfg = ConfigBuilder::default().line_width(20).build();
+    let input = "| The limerick packs laughs anatomical\n|    In space that is quite economical.\n";
+    let output = format(input, Some(cfg));
+    similar_asserts::assert_eq!(output, input);
+}
+
+#[test]
+fn line_block_keeps_continuations_and_empty_lines() {
+    let input = "| 200 Main St.\n|\n| Springfield,\n  USA\n\nAfter.\n";
+    let output = format(input, None);
+    similar_asserts::assert_eq!(output, input);
+}
+
+#[test]
+fn line_block_trailing_whitespace_is_removed() {
+    let input = "| Line one   \n|  \n| Line two\t\n";
+    let expected = "| Line one\n|\n| Line two\n";
+    let output = format(input, None);
+    similar_asserts::assert_eq!(output, expected);
+    similar_asserts::assert_eq!(format(&output, None), expected);
+}
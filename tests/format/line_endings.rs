@@ -0,0 +1,58 @@
+use quartofmt::{ConfigBuilder, LineEnding, format};
+
+const LF_INPUT: &str = "# Title\n\nSome text\nover two lines.\n";
+const CRLF_INPUT: &str = "# Title\r\n\r\nSome text\r\nover two lines.\r\n";
+const MIXED_INPUT: &str = "# Title\r\n\nSome text\nover two lines.\r\n";
+
+const LF_OUTPUT: &str = "# Title\n\nSome text over two lines.\n";
+const CRLF_OUTPUT: &str = "# Title\r\n\r\nSome text over two lines.\r\n";
+
+#[test]
+fn auto_keeps_input_line_endings() {
+    let cfg = ConfigBuilder::default()
+        .line_ending(LineEnding::Auto)
+        .build();
+    similar_asserts::assert_eq!(format(LF_INPUT, Some(cfg.clone())), LF_OUTPUT);
+    similar_asserts::assert_eq!(format(CRLF_INPUT, Some(cfg)), CRLF_OUTPUT);
+}
+
+#[test]
+fn auto_uses_first_line_ending_for_mixed_input() {
+    let cfg = ConfigBuilder::default()
+        .line_ending(LineEnding::Auto)
+        .build();
+    similar_asserts::assert_eq!(format(MIXED_INPUT, Some(cfg.clone())), CRLF_OUTPUT);
+
+    let mixed_lf_first = "# Title\n\r\nSome text\r\nover two lines.\n";
+    similar_asserts::assert_eq!(format(mixed_lf_first, Some(cfg)), LF_OUTPUT);
+}
+
+#[test]
+fn lf_is_forced() {
+    let cfg = ConfigBuilder::default().line_ending(LineEnding::Lf).build();
+    for input in [LF_INPUT, CRLF_INPUT, MIXED_INPUT] {
+        similar_asserts::assert_eq!(format(input, Some(cfg.clone())), LF_OUTPUT);
+    }
+}
+
+#[test]
+fn crlf_is_forced() {
+    let cfg = ConfigBuilder::default()
+        .line_ending(LineEnding::Crlf)
+        .build();
+    for input in [LF_INPUT, CRLF_INPUT, MIXED_INPUT] {
+        similar_asserts::assert_eq!(format(input, Some(cfg.clone())), CRLF_OUTPUT);
+    }
+}
+
+#[test]
+fn default_config_is_auto() {
+    similar_asserts::assert_eq!(format(CRLF_INPUT, None), CRLF_OUTPUT);
+    similar_asserts::assert_eq!(format(LF_INPUT, None), LF_OUTPUT);
+}
+
+#[test]
+fn line_ending_is_read_from_toml() {
+    let cfg: quartofmt::Config = toml::from_str("line_ending = \"crlf\"").unwrap();
+    assert_eq!(cfg.line_ending, Some(LineEnding::Crlf));
+}
@@ -1,4 +1,4 @@
-use quartofmt::format;
+use quartofmt::{LinkReferenceMode, format};
 
 #[test]
 fn markdown_link_no_break() {
@@ -31,3 +31,93 @@ fn markdown_link_no_break() {
     // The link should still be functional
     assert!(output2.contains("https://example.com"));
 }
+
+#[test]
+fn link_text_wraps_but_url_does_not() {
+    let cfg = quartofmt::ConfigBuilder::default().line_width(20).build();
+    let input = "See [the very long link text here](https://example.com/a/very/long/path/that/is/longer/than/the/width) for more.\n";
+    let output = format(input, Some(cfg));
+    similar_asserts::assert_eq!(
+        output,
+        "See [the very\nlong link text\nhere](https://example.com/a/very/long/path/that/is/longer/than/the/width)\nfor more.\n"
+    );
+}
+
+#[test]
+fn link_title_is_kept_together() {
+    let cfg = quartofmt::ConfigBuilder::default().line_width(20).build();
+    let input = "A [link](https://example.com \"with a title\") here.\n";
+    let output = format(input, Some(cfg));
+    assert!(output.contains("[link](https://example.com \"with a title\")"));
+}
+
+#[test]
+fn image_attributes_stay_attached() {
+    let cfg = quartofmt::ConfigBuilder::default().line_width(20).build();
+    let input = "An image ![alt text](figure.png){width=50%} in a paragraph.\n";
+    let output = format(input, Some(cfg));
+    assert!(output.contains("text](figure.png){width=50%}"));
+}
+
+#[test]
+fn autolink_is_never_broken() {
+    let cfg = quartofmt::ConfigBuilder::default().line_width(20).build();
+    let input = "Visit <https://example.com/some/long/path> today.\n";
+    let output = format(input, Some(cfg));
+    similar_asserts::assert_eq!(
+        output,
+        "Visit\n<https://example.com/some/long/path>\ntoday.\n"
+    );
+}
+
+#[test]
+fn reference_links_are_kept() {
+    let input = "A [full][ref], a [collapsed][] and a [shortcut] link.\n\n[ref]: https://example.com\n[collapsed]: https://example.com\n[shortcut]: https://example.com\n";
+    let output = format(input, None);
+    assert!(output.starts_with("A [full][ref], a [collapsed][] and a [shortcut] link.\n"));
+}
+
+#[test]
+fn link_reference_definitions_stay_on_their_own_lines() {
+    let cfg = quartofmt::ConfigBuilder::default().line_width(100).build();
+    let input = "[a]: https://a.example.com\n[b]:   https://b.example.com   \"B\"\n[c]:\n  <https://c.example.com>\n  'C' {.external}\n";
+    let expected = "[a]: https://a.example.com\n[b]: https://b.example.com \"B\"\n[c]: <https://c.example.com> 'C' {.external}\n";
+    let output = format(input, Some(cfg));
+    similar_asserts::assert_eq!(output, expected);
+}
+
+#[test]
+fn link_reference_definitions_can_be_sorted() {
+    let cfg = quartofmt::ConfigBuilder::default()
+        .link_reference_definitions(LinkReferenceMode::Sort)
+        .build();
+    let input = "Text.\n\n[zeta]: https://z.example.com\n[Alpha]: https://a.example.com\n[beta]: https://b.example.com\n\nMore.\n\n[d]: https://d.example.com\n[c]: https://c.example.com\n";
+    let expected = "Text.\n\n[Alpha]: https://a.example.com\n[beta]: https://b.example.com\n[zeta]: https://z.example.com\n\nMore.\n\n[c]: https://c.example.com\n[d]: https://d.example.com\n";
+    let output = format(input, Some(cfg.clone()));
+    similar_asserts::assert_eq!(output, expected);
+    similar_asserts::assert_eq!(format(&output, Some(cfg)), expected);
+}
+
+#[test]
+fn link_reference_definitions_can_be_collected() {
+    let cfg = quartofmt::ConfigBuilder::default()
+        .link_reference_definitions(LinkReferenceMode::Collect)
+        .build();
+    let input = "# Intro\n\nSee [a].\n\n[a]: https://a.example.com\n\n# Next\n\nSee [b].\n\n[b]: https://b.example.com\n\nThe end.\n";
+    let expected = "# Intro\n\nSee [a].\n\n# Next\n\nSee [b].\n\nThe end.\n\n[a]: https://a.example.com\n[b]: https://b.example.com\n";
+    let output = format(input, Some(cfg.clone()));
+    similar_asserts::assert_eq!(output, expected);
+    similar_asserts::assert_eq!(format(&output, Some(cfg)), expected);
+}
+
+#[test]
+fn collect_leaves_definitions_in_containers_in_place() {
+    let cfg = quartofmt::ConfigBuilder::default()
+        .link_reference_definitions(LinkReferenceMode::Collect)
+        .build();
+    let input = "- item [a]\n\n  [a]: http://a\n\n> [b]: http://b\n\nPara [b].\n\n[c]: http://c\n\nEnd [c].\n";
+    let expected = "- item [a]\n\n  [a]: http://a\n\n> [b]: http://b\n\nPara [b].\n\nEnd [c].\n\n[c]: http://c\n";
+    let output = format(input, Some(cfg.clone()));
+    similar_asserts::assert_eq!(output, expected);
+    similar_asserts::assert_eq!(format(&output, Some(cfg)), expected);
+}
@@ -0,0 +1,44 @@
+use quartofmt::format;
+
+#[test]
+fn ordered_list_hanging_indent() {
+    let cfg = quartofmt::ConfigBuilder::default().line_width(20).build();
+    let input = "1. first item\n   text\n2. second\n";
+    let expected = "1. first item text\n2. second\n";
+    let output = format(input, Some(cfg));
+    similar_asserts::assert_eq!(output, expected);
+}
+
+#[test]
+fn lazy_continuation_is_indented() {
+    let cfg = quartofmt::ConfigBuilder::default().line_width(30).build();
+    let input = "10) an item that goes on for a while\nlazily continued\n";
+    let output = format(input, Some(cfg));
+    for line in output.lines().skip(1) {
+        assert!(line.starts_with("    "), "not indented: '{line}'");
+    }
+}
+
+#[test]
+fn loose_list_keeps_single_blank_lines() {
+    let input = "1. one\n\n\n2. two\n\n   continued\n";
+    let expected = "1. one\n\n2. two\n\n   continued\n";
+    let output = format(input, None);
+    similar_asserts::assert_eq!(output, expected);
+}
+
+#[test]
+fn nested_mixed_lists_roundtrip() {
+    let input = "1. one\n   - a\n   - b\n2. two\n   (i) x\n   (ii) y\n\nAfter the list.\n";
+    let output = format(input, None);
+    similar_asserts::assert_eq!(output, input);
+}
+
+#[test]
+fn list_after_thematic_break_is_stable() {
+    let input = "---\n* star\n2) two\n";
+    let expected = "---\n\n* star\n2) two\n";
+    let output = format(input, None);
+    similar_asserts::assert_eq!(output, expected);
+    similar_asserts::assert_eq!(format(&output, None), expected);
+}
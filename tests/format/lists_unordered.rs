@@ -30,3 +30,37 @@ fn list_items_separate_properly() {
     // Should not merge the list items
     assert!(!output.contains("is created. - For instance"));
 }
+
+#[test]
+fn nested_list_is_indented_under_parent_content() {
+    let input = "- outer\n    - inner\n\n        continued\n- outer two\n";
+    let expected = "- outer\n  - inner\n\n    continued\n- outer two\n";
+    let output = format(input, None);
+    similar_asserts::assert_eq!(output, expected);
+    similar_asserts::assert_eq!(format(&output, None), expected);
+}
+
+#[test]
+fn tight_list_stays_tight() {
+    let input = "* one\n* two\n* three\n";
+    let output = format(input, None);
+    similar_asserts::assert_eq!(output, input);
+}
+
+#[test]
+fn wrapping_never_starts_a_line_with_a_list_marker() {
+    let cfg = quartofmt::ConfigBuilder::default().line_width(30).build();
+    let input = "- aaaaaaaaaaaaaaaaaaaaaaaaa bb - b c\n";
+    let output = format(input, Some(cfg.clone()));
+    similar_asserts::assert_eq!(output, "- aaaaaaaaaaaaaaaaaaaaaaaaa\n  bb - b c\n");
+    similar_asserts::assert_eq!(format(&output, Some(cfg)), output);
+}
+
+#[test]
+fn lazy_line_after_overindented_continuation_stays_in_item() {
+    let input = "- item text\n    more text\nlazy text\n";
+    let expected = "- item text more text lazy text\n";
+    let output = format(input, None);
+    similar_asserts::assert_eq!(output, expected);
+    similar_asserts::assert_eq!(format(&output, None), expected);
+}
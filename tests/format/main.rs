@@ -1,10 +1,21 @@
+mod citations;
+mod code_blocks;
 mod comments;
+mod definition_lists;
+mod fenced_divs;
+mod footnotes;
 mod frontmatter;
 mod headings;
+mod html;
+mod line_blocks;
+mod line_endings;
 mod links;
+mod lists_ordered;
 mod lists_unordered;
 mod math;
 mod paragraphs;
 mod preserve_wrap;
 mod quotes;
+mod tables;
 mod tex;
+mod thematic_breaks;
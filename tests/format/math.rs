@@ -10,3 +10,72 @@ fn math_no_wrap() {
     // Math blocks should not be wrapped
     similar_asserts::assert_eq!(output, input);
 }
+
+#[test]
+fn math_block_label_is_kept_on_closing_line() {
+    let input = "$$\nE = mc^2\n$$ {#eq-energy}\n";
+    let output = format(input, None);
+    similar_asserts::assert_eq!(output, input);
+}
+
+#[test]
+fn single_line_math_block_is_expanded() {
+    let input = "$$ E = mc^2 $$ {#eq-energy}\n";
+    let expected = "$$\nE = mc^2\n$$ {#eq-energy}\n";
+    let output = format(input, None);
+    similar_asserts::assert_eq!(output, expected);
+}
+
+#[test]
+fn math_indent_is_applied() {
+    let cfg = ConfigBuilder::default().math_indent(2).build();
+    let input = "$$\n    a &= b \\\\\n      &= c\n$$\n";
+    let expected = "$$\n  a &= b \\\\\n    &= c\n$$\n";
+    let output = format(input, Some(cfg.clone()));
+    similar_asserts::assert_eq!(output, expected);
+    similar_asserts::assert_eq!(format(&output, Some(cfg)), expected);
+}
+
+#[test]
+fn math_block_between_paragraph_lines() {
+    let input = "We have\n$$\nx = y\n$$\nfor all x.\n";
+    let output = format(input, None);
+    similar_asserts::assert_eq!(output, input);
+}
+
+#[test]
+fn inline_math_is_never_broken() {
+    let cfg = ConfigBuilder::default().line_width(20).build();
+    let input = "The identity $a + b = b + a$ holds for all numbers.\n";
+    let output = format(input, Some(cfg));
+    similar_asserts::assert_eq!(
+        output,
+        "The identity\n$a + b = b + a$\nholds for all\nnumbers.\n"
+    );
+}
+
+#[test]
+fn inline_math_across_lines_is_joined() {
+    let input = "The identity $a +\nb = b + a$ holds.\n";
+    let output = format(input, None);
+    similar_asserts::assert_eq!(output, "The identity $a + b = b + a$ holds.\n");
+}
+
+#[test]
+fn dollar_amounts_can_wrap() {
+    let cfg = ConfigBuilder::default().line_width(20).build();
+    let input = "It costs $20,000 or maybe $30,000 in total.\n";
+    let output = format(input, Some(cfg));
+    similar_asserts::assert_eq!(output, "It costs $20,000\nor maybe $30,000 in\ntotal.\n");
+}
+
+#[test]
+fn math_indent_counts_only_spaces_and_tabs() {
+    // An ideographic space is content, not indentation
+    let input = "$$\n\u{3000}x = 1\n y = 2\n$$\n";
+    similar_asserts::assert_eq!(format(input, None), input);
+
+    let input = "$$\n\tx\n    y\n$$\n";
+    let expected = "$$\nx\ny\n$$\n";
+    similar_asserts::assert_eq!(format(input, None), expected);
+}
@@ -13,3 +13,63 @@ fn preserves_inline_math_whitespace() {
     let output = format(input, None);
     similar_asserts::assert_eq!(output, "Math: $x   +   y$\n");
 }
+
+#[test]
+fn long_paragraph_is_wrapped() {
+    let cfg = quartofmt::ConfigBuilder::default().line_width(20).build();
+    let input = "This paragraph is long enough that it needs to be wrapped.\n";
+    let expected = "This paragraph is\nlong enough that it\nneeds to be wrapped.\n";
+    let output = format(input, Some(cfg));
+    similar_asserts::assert_eq!(output, expected);
+}
+
+#[test]
+fn code_span_is_never_broken() {
+    let cfg = quartofmt::ConfigBuilder::default().line_width(20).build();
+    let input = "Call `some_function(with, many, args)` now.\n";
+    let output = format(input, Some(cfg));
+    assert!(output.contains("`some_function(with, many, args)`"));
+}
+
+#[test]
+fn emphasis_markers_stay_attached() {
+    let cfg = quartofmt::ConfigBuilder::default().line_width(12).build();
+    let input = "aaaa bbbb *emphasized words here* and **strong text**.\n";
+    let output = format(input, Some(cfg));
+    for line in output.lines() {
+        assert!(!line.starts_with("* ") && !line.ends_with(" *"), "{line}");
+        assert!(!line.starts_with("** ") && !line.ends_with(" **"), "{line}");
+    }
+    // Emphasis content itself may wrap
+    assert!(output.contains("*emphasized\n"));
+}
+
+#[test]
+fn hard_line_breaks_are_kept() {
+    let input = "First line\\\nsecond line  \nthird line\n";
+    let output = format(input, None);
+    similar_asserts::assert_eq!(output, input);
+}
+
+#[test]
+fn escaped_space_is_not_a_break_point() {
+    let cfg = quartofmt::ConfigBuilder::default().line_width(10).build();
+    let input = "Mr.\\ Smith and Dr.\\ Jones\n";
+    let output = format(input, Some(cfg));
+    assert!(output.contains("Mr.\\ Smith"));
+    assert!(output.contains("Dr.\\ Jones"));
+}
+
+#[test]
+fn wrapping_never_starts_a_line_with_a_block_marker() {
+    let cfg = quartofmt::ConfigBuilder::default().line_width(12).build();
+    for marker in ["#", "1.", "a)", "$$", ":::", "[^1]:", ">", "```"] {
+        let input = format!("aaaaaaaaaa {marker} b aaaaaaaaaa {marker} c\n");
+        let output = format(&input, Some(cfg.clone()));
+        assert!(
+            output.lines().skip(1).all(|line| !line.starts_with(marker)),
+            "{marker}: {output}"
+        );
+        similar_asserts::assert_eq!(format(&output, Some(cfg.clone())), output);
+    }
+}
@@ -23,3 +23,44 @@ fn quote_multi_line_continuous() {
     assert!(output.contains("multi-line quote"));
     assert!(output.contains("continues on the next line"));
 }
+
+#[test]
+fn quote_with_heading_code_and_list() {
+    let input = "> # Heading\n>\n> ```python\n> print(\"hi\")\n> ```\n>\n> - one\n> - two\n";
+    let output = format(input, None);
+    similar_asserts::assert_eq!(output, input);
+}
+
+#[test]
+fn nested_quote_is_wrapped_within_markers() {
+    let cfg = quartofmt::ConfigBuilder::default().line_width(24).build();
+    let input = "> Outer\n>\n> > A nested quote that needs wrapping.\n";
+    let expected = "> Outer\n>\n> > A nested quote that\n> > needs wrapping.\n";
+    let output = format(input, Some(cfg.clone()));
+    similar_asserts::assert_eq!(output, expected);
+    similar_asserts::assert_eq!(format(&output, Some(cfg)), expected);
+}
+
+#[test]
+fn lazy_continuation_is_quoted() {
+    let input = "> A quote\nthat continues lazily.\n";
+    let expected = "> A quote that continues lazily.\n";
+    let output = format(input, None);
+    similar_asserts::assert_eq!(output, expected);
+}
+
+#[test]
+fn quote_in_list_item() {
+    let input = "- Item\n\n  > Quoted text\n";
+    let output = format(input, None);
+    similar_asserts::assert_eq!(output, input);
+}
+
+#[test]
+fn blockquote_after_heading_is_stable() {
+    let input = "# Heading\n> Quoted text.\n";
+    let expected = "# Heading\n\n> Quoted text.\n";
+    let output = format(input, None);
+    similar_asserts::assert_eq!(output, expected);
+    similar_asserts::assert_eq!(format(&output, None), expected);
+}
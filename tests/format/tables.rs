@@ -0,0 +1,160 @@
+use quartofmt::{ConfigBuilder, GridTableMode, format};
+
+#[test]
+fn pipe_table_columns_are_aligned() {
+    let input = "| Right | Left | Default | Center |\n|------:|:-----|---------|:------:|\n|   12  |  12  |    12   |    12  |\n| 123 | 123 | 123 | 123 |\n";
+    let expected = "| Right | Left | Default | Center |\n|------:|:-----|---------|:------:|\n|    12 | 12   | 12      |   12   |\n|   123 | 123  | 123     |  123   |\n";
+    let output = format(input, None);
+    similar_asserts::assert_eq!(output, expected);
+    similar_asserts::assert_eq!(format(&output, None), expected);
+}
+
+#[test]
+fn pipe_table_uses_display_width() {
+    let input = "| a | b |\n|---|---|\n| 日本語 | x |\n";
+    let expected = "| a      | b   |\n|--------|-----|\n| 日本語 | x   |\n";
+    let output = format(input, None);
+    similar_asserts::assert_eq!(output, expected);
+}
+
+#[test]
+fn pipe_table_is_not_wrapped() {
+    let cfg = ConfigBuilder::default().line_width(10).build();
+    let input = "| a long header | another one |\n|---|---|\n| x | y |\n";
+    let output = format(input, Some(cfg));
+    assert!(output.starts_with("| a long header | another one |\n"));
+}
+
+#[test]
+fn wide_pipe_table_keeps_its_dashes() {
+    let cfg = ConfigBuilder::default().line_width(20).build();
+    let input = "| a | b |\n|-|---:|\n| a long cell | another long cell |\n";
+    let expected =
+        "| a           |                 b |\n|-|---:|\n| a long cell | another long cell |\n";
+    let output = format(input, Some(cfg.clone()));
+    similar_asserts::assert_eq!(output, expected);
+    similar_asserts::assert_eq!(format(&output, Some(cfg)), expected);
+}
+
+#[test]
+fn pipes_in_code_spans_stay_in_their_cell() {
+    let input = "| `a|b` | c |\n|---|---|\n| 1 | 2 |\n";
+    let expected = "| `a|b` | c   |\n|-------|-----|\n| 1     | 2   |\n";
+    let output = format(input, None);
+    similar_asserts::assert_eq!(output, expected);
+}
+
+#[test]
+fn pipe_table_caption_is_kept() {
+    let input = "| a |\n|---|\n| 1 |\n: Numbers {#tbl-numbers}\n";
+    let expected = "| a   |\n|-----|\n| 1   |\n\n: Numbers {#tbl-numbers}\n";
+    let output = format(input, None);
+    similar_asserts::assert_eq!(output, expected);
+}
+
+#[test]
+fn short_rows_are_padded() {
+    let input = "a | b | c\n--|--|--\n1 | 2\n";
+    let expected = "| a   | b   | c   |\n|-----|-----|-----|\n| 1   | 2   |     |\n";
+    let output = format(input, None);
+    similar_asserts::assert_eq!(output, expected);
+}
+
+const GRID_TABLE: &str = "\
++---------------+-------+
+| Fruit         | Price |
++===============+=======+
+| Bananas and more | $1.34 |
+|               | each  |
++---------------+-------+
+| A spanning cell       |
++-----------------------+
+";
+
+#[test]
+fn grid_table_is_preserved_by_default() {
+    let cfg = ConfigBuilder::default().line_width(10).build();
+    let output = format(GRID_TABLE, Some(cfg));
+    similar_asserts::assert_eq!(output, GRID_TABLE);
+}
+
+#[test]
+fn grid_table_is_redrawn() {
+    let cfg = ConfigBuilder::default()
+        .grid_tables(GridTableMode::Redraw)
+        .build();
+    let expected = "\
++------------------+-------+
+| Fruit            | Price |
++==================+=======+
+| Bananas and more | $1.34 |
+|                  | each  |
++------------------+-------+
+| A spanning cell          |
++--------------------------+
+";
+    let output = format(GRID_TABLE, Some(cfg.clone()));
+    similar_asserts::assert_eq!(output, expected);
+    similar_asserts::assert_eq!(format(&output, Some(cfg)), expected);
+}
+
+#[test]
+fn grid_table_spanning_cell_widens_columns() {
+    let cfg = ConfigBuilder::default()
+        .grid_tables(GridTableMode::Redraw)
+        .build();
+    let input = "+---+---+\n| a | b |\n+---+---+\n| a long spanning cell |\n+---+---+\n";
+    let expected = "+---+------------------+\n| a | b                |\n+---+------------------+\n| a long spanning cell |\n+---+------------------+\n";
+    let output = format(input, Some(cfg));
+    similar_asserts::assert_eq!(output, expected);
+}
+
+#[test]
+fn grid_table_alignment_colons_are_kept() {
+    let cfg = ConfigBuilder::default()
+        .grid_tables(GridTableMode::Redraw)
+        .build();
+    let input = "+----+\n| a |\n+:==:+\n| 1 |\n+----+\n";
+    let expected = "+---+\n| a |\n+:=:+\n| 1 |\n+---+\n";
+    let output = format(input, Some(cfg));
+    similar_asserts::assert_eq!(output, expected);
+}
+
+#[test]
+fn grid_table_with_row_spans_is_redrawn() {
+    let cfg = ConfigBuilder::default()
+        .grid_tables(GridTableMode::Redraw)
+        .build();
+    let input = "+---+---+\n| a | b |\n|   +:-:+\n|   | longer |\n+---+---+\n";
+    let expected = "+---+--------+\n| a | b      |\n|   +:------:+\n|   | longer |\n+---+--------+\n";
+    let output = format(input, Some(cfg.clone()));
+    similar_asserts::assert_eq!(output, expected);
+    similar_asserts::assert_eq!(format(&output, Some(cfg)), expected);
+}
+
+#[test]
+fn grid_table_row_span_in_last_column_is_redrawn() {
+    let cfg = ConfigBuilder::default()
+        .grid_tables(GridTableMode::Redraw)
+        .build();
+    let input = "+---+---+\n| a | b |\n+---+   +\n| longer |   |\n+---+---+\n";
+    let expected = "+--------+---+\n| a      | b |\n+--------+   +\n| longer |   |\n+--------+---+\n";
+    let output = format(input, Some(cfg));
+    similar_asserts::assert_eq!(output, expected);
+}
+
+#[test]
+fn simple_table_is_not_reflowed() {
+    let cfg = ConfigBuilder::default().line_width(10).build();
+    let input = "  Right     Left     Center\n-------     ------ ----------\n     12     12        12\n\nTable: A caption that is longer than the line width\n";
+    let output = format(input, Some(cfg));
+    similar_asserts::assert_eq!(output, input);
+}
+
+#[test]
+fn multiline_table_is_not_reflowed() {
+    let cfg = ConfigBuilder::default().line_width(10).build();
+    let input = "----------------------------\n Centered   Left\n  Header    Aligned\n----------- ----------------\n   First    Example of a row\n            on two lines.\n\n  Second    Another row.\n----------------------------\n\nText after.\n";
+    let output = format(input, Some(cfg));
+    similar_asserts::assert_eq!(output, input.replace("Text after.", "Text\nafter."));
+}
@@ -38,3 +38,28 @@ fn mixed_latex_and_markdown() {
     assert!(output.contains("\\LaTeX{}"));
     assert!(output.contains("https://example.com"));
 }
+
+#[test]
+fn inline_command_is_atomic() {
+    let cfg = quartofmt::ConfigBuilder::default().line_width(30).build();
+    let input = "Some text with \\pdfpcnote{a long note that should stay together} after.\n";
+    let expected = "Some text with\n\\pdfpcnote{a long note that should stay together}\nafter.\n";
+    let output = format(input, Some(cfg));
+    similar_asserts::assert_eq!(output, expected);
+}
+
+#[test]
+fn latex_environment_is_preserved() {
+    let cfg = quartofmt::ConfigBuilder::default().line_width(20).build();
+    let input = "\\begin{align}\n  a &= b + c + d + e + f \\\\\n\n  \\begin{align}x\\end{align}\n\\end{align}\n\nText.\n";
+    let output = format(input, Some(cfg));
+    similar_asserts::assert_eq!(output, input);
+}
+
+#[test]
+fn standalone_commands_are_preserved() {
+    let cfg = quartofmt::ConfigBuilder::default().line_width(20).build();
+    let input = "\\newpage\n\\includegraphics[width=0.5\\textwidth]{figure.png}\n\nText.\n";
+    let output = format(input, Some(cfg));
+    similar_asserts::assert_eq!(output, input);
+}
@@ -0,0 +1,43 @@
+use quartofmt::{ConfigBuilder, ThematicBreakStyle, format};
+
+#[test]
+fn thematic_break_is_not_wrapped_into_paragraph() {
+    let input = "First paragraph.\n\n---\n\nSecond paragraph.\n";
+    let output = format(input, None);
+    similar_asserts::assert_eq!(output, input);
+}
+
+#[test]
+fn thematic_break_whitespace_is_trimmed() {
+    let input = "Text\n\n   * * *   \n\nText\n";
+    let expected = "Text\n\n* * *\n\nText\n";
+    let output = format(input, None);
+    similar_asserts::assert_eq!(output, expected);
+}
+
+#[test]
+fn thematic_break_style_is_normalized() {
+    let input = "Text\n\n* * *\n\nText\n\n_____\n";
+    for (style, marker) in [
+        (ThematicBreakStyle::Dashes, "---"),
+        (ThematicBreakStyle::Asterisks, "***"),
+        (ThematicBreakStyle::Underscores, "___"),
+    ] {
+        let cfg = ConfigBuilder::default().thematic_break_style(style).build();
+        let expected = format!("Text\n\n{marker}\n\nText\n\n{marker}\n");
+        let output = format(input, Some(cfg.clone()));
+        similar_asserts::assert_eq!(output, expected);
+        similar_asserts::assert_eq!(format(&output, Some(cfg)), expected);
+    }
+}
+
+#[test]
+fn blank_line_is_added_after_thematic_break() {
+    let cfg = ConfigBuilder::default()
+        .thematic_break_style(ThematicBreakStyle::Dashes)
+        .build();
+    let input = "Text\n\n***\nMore text\n";
+    let expected = "Text\n\n---\n\nMore text\n";
+    let output = format(input, Some(cfg));
+    similar_asserts::assert_eq!(output, expected);
+}